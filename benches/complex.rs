@@ -0,0 +1,22 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use num_complex::Complex;
+use polyeval::{horner, horner_real_at_complex};
+use std::hint::black_box;
+
+fn bench_complex(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..64).map(|k| 1.0 / (k + 1) as f64).collect();
+    let complex_coeffs: Vec<Complex<f64>> = coeffs.iter().map(|&c| Complex::new(c, 0.0)).collect();
+    let x = Complex::new(0.6, 0.7);
+
+    let mut group = c.benchmark_group("real coefficients at a complex point");
+    group.bench_function("horner", |b| {
+        b.iter(|| horner(black_box(x), black_box(&complex_coeffs)))
+    });
+    group.bench_function("horner_real_at_complex", |b| {
+        b.iter(|| horner_real_at_complex(black_box(x.re), black_box(x.im), black_box(&coeffs)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_complex);
+criterion_main!(benches);
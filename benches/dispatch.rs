@@ -0,0 +1,23 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{horner, horner_auto, horner_fma};
+use std::hint::black_box;
+
+fn bench_horner_auto(c: &mut Criterion) {
+    for n in [8, 32, 128] {
+        let coeffs: Vec<f64> = (0..n).map(|k| 1.0 / (k + 1) as f64).collect();
+        let mut group = c.benchmark_group(format!("{n} coefficients"));
+        group.bench_function("horner", |b| {
+            b.iter(|| horner(black_box(0.5), black_box(&coeffs)))
+        });
+        group.bench_function("horner_fma", |b| {
+            b.iter(|| horner_fma(black_box(0.5), black_box(&coeffs)))
+        });
+        group.bench_function("horner_auto", |b| {
+            b.iter(|| horner_auto(black_box(0.5), black_box(&coeffs)))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_horner_auto);
+criterion_main!(benches);
@@ -0,0 +1,23 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{estrin_many, horner_many};
+use std::hint::black_box;
+
+fn bench_estrin_many(c: &mut Criterion) {
+    let xs: Vec<f64> = (0..1024).map(|k| k as f64 / 1024.0).collect();
+    let mut out = vec![0.0; xs.len()];
+
+    for n in [8, 32, 128] {
+        let coeffs: Vec<f64> = (0..n).map(|k| 1.0 / (k + 1) as f64).collect();
+        let mut group = c.benchmark_group(format!("1024 points, {n} coefficients"));
+        group.bench_function("horner_many", |b| {
+            b.iter(|| horner_many(black_box(&xs), black_box(&coeffs), &mut out))
+        });
+        group.bench_function("estrin_many", |b| {
+            b.iter(|| estrin_many(black_box(&xs), black_box(&coeffs), &mut out))
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_estrin_many);
+criterion_main!(benches);
@@ -0,0 +1,24 @@
+//! Set `RAYON_NUM_THREADS` to compare the scaling across different numbers of cores.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{horner_many, horner_par};
+use std::hint::black_box;
+
+fn bench_par(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..51).map(|k| 1.0 / (k + 1) as f64).collect();
+    let xs: Vec<f64> = (0..1 << 20).map(|k| k as f64 / (1 << 20) as f64).collect();
+    let mut out = vec![0.0; xs.len()];
+
+    let mut group = c.benchmark_group("a million points");
+    group.sample_size(20);
+    group.bench_function("horner_many", |b| {
+        b.iter(|| horner_many(black_box(&xs), black_box(&coeffs), &mut out))
+    });
+    group.bench_function("horner_par", |b| {
+        b.iter(|| horner_par(black_box(&xs), black_box(&coeffs)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_par);
+criterion_main!(benches);
@@ -0,0 +1,21 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{horner, horner_prefetch};
+use std::hint::black_box;
+
+fn bench_prefetch(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..1 << 24).map(|k| 1.0 / (k % 1000 + 1) as f64).collect();
+    let x = 0.999_999;
+
+    let mut group = c.benchmark_group("large slice");
+    group.sample_size(20);
+    group.bench_function("horner", |b| {
+        b.iter(|| horner(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_prefetch", |b| {
+        b.iter(|| horner_prefetch(black_box(x), black_box(&coeffs)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_prefetch);
+criterion_main!(benches);
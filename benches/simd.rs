@@ -0,0 +1,54 @@
+//! Requires a nightly compiler and the feature `simd`.
+
+#![feature(portable_simd)]
+
+use core::simd::Simd;
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{horner, horner_simd_f32x8, horner_simd_f64x4};
+use std::hint::black_box;
+
+fn bench_simd(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..16).map(|k| 1.0 / (k + 1) as f64).collect();
+    let coeffs_f32: Vec<f32> = coeffs.iter().map(|&c| c as f32).collect();
+    let xs: Vec<f64> = (0..1024).map(|k| k as f64 / 1024.0).collect();
+    let xs_f32: Vec<f32> = xs.iter().map(|&x| x as f32).collect();
+
+    let mut group = c.benchmark_group("1024 points f32");
+    group.bench_function("horner", |b| {
+        b.iter(|| {
+            xs_f32
+                .iter()
+                .map(|&x| horner(black_box(x), black_box(&coeffs_f32)))
+                .sum::<f32>()
+        })
+    });
+    group.bench_function("horner_simd_f32x8", |b| {
+        b.iter(|| {
+            xs_f32
+                .chunks_exact(8)
+                .map(|x| horner_simd_f32x8(black_box(Simd::from_slice(x)), black_box(&coeffs_f32)))
+                .sum::<Simd<f32, 8>>()
+        })
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("1024 points f64");
+    group.bench_function("horner", |b| {
+        b.iter(|| {
+            xs.iter()
+                .map(|&x| horner(black_box(x), black_box(&coeffs)))
+                .sum::<f64>()
+        })
+    });
+    group.bench_function("horner_simd_f64x4", |b| {
+        b.iter(|| {
+            xs.chunks_exact(4)
+                .map(|x| horner_simd_f64x4(black_box(Simd::from_slice(x)), black_box(&coeffs)))
+                .sum::<Simd<f64, 4>>()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_simd);
+criterion_main!(benches);
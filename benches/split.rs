@@ -0,0 +1,32 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::{horner, horner_2way, horner_even_odd, horner_kway};
+use std::hint::black_box;
+
+fn bench_split(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..256).map(|k| 1.0 / (k + 1) as f64).collect();
+    let x = 0.999;
+
+    let mut group = c.benchmark_group("256 coefficients");
+    group.bench_function("horner", |b| {
+        b.iter(|| horner(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_even_odd", |b| {
+        b.iter(|| horner_even_odd(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_2way", |b| {
+        b.iter(|| horner_2way(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_kway 3", |b| {
+        b.iter(|| horner_kway::<3, _>(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_kway 4", |b| {
+        b.iter(|| horner_kway::<4, _>(black_box(x), black_box(&coeffs)))
+    });
+    group.bench_function("horner_kway 8", |b| {
+        b.iter(|| horner_kway::<8, _>(black_box(x), black_box(&coeffs)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_split);
+criterion_main!(benches);
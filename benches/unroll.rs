@@ -0,0 +1,25 @@
+//! Compare the timings with and without one of the features `unroll-4`, `unroll-8`, `unroll-16`.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use polyeval::horner;
+use std::hint::black_box;
+
+fn bench_unroll(c: &mut Criterion) {
+    let coeffs: Vec<f64> = (0..16).map(|k| 1.0 / (k + 1) as f64).collect();
+    let xs: Vec<f64> = (0..1024).map(|k| k as f64 / 1024.0).collect();
+
+    let mut group = c.benchmark_group("low degree");
+    for n in [4, 8, 16] {
+        group.bench_function(format!("horner {n} coefficients"), |b| {
+            b.iter(|| {
+                xs.iter()
+                    .map(|&x| horner(black_box(x), black_box(&coeffs[..n])))
+                    .sum::<f64>()
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_unroll);
+criterion_main!(benches);
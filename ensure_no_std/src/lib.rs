@@ -0,0 +1,95 @@
+//! Exercises the fallible API of `polyeval` in a `no_std` crate without `alloc`.
+//!
+//! The error types must be small `Copy` values without heap content,
+//! and reporting them must not require allocations.
+//!
+//! With the `libm` feature it also exercises the fused multiply-add of `libm`.
+
+#![no_std]
+
+use core::fmt::{self, Write};
+
+use polyeval::{horner_overflow_degree, try_horner_codebook};
+
+/// Fixed-capacity buffer for formatting messages on the stack.
+pub struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    pub const fn new() -> Self {
+        Buffer {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Evaluates an integer polynomial, reporting the overflowing degree in `message`.
+pub fn overflow_degree(x: i64, coeffs: &[i64], message: &mut Buffer) -> Option<i64> {
+    match horner_overflow_degree(x, coeffs) {
+        Ok(y) => Some(y),
+        Err(k) => {
+            let _ = write!(message, "overflow at degree {k}");
+            None
+        }
+    }
+}
+
+/// Evaluates a polynomial stored through a codebook, reporting an invalid index in `message`.
+pub fn codebook(x: f64, indices: &[u8], codebook: &[f64], message: &mut Buffer) -> Option<f64> {
+    let y = try_horner_codebook(x, indices, codebook);
+    if y.is_none() {
+        let _ = write!(message, "index out of range for {} entries", codebook.len());
+    }
+    y
+}
+
+/// Evaluates a polynomial with a fused multiply-add at every step, through `libm::fma`.
+#[cfg(feature = "libm")]
+pub fn fused(x: f64, coeffs: &[f64]) -> f64 {
+    polyeval::horner_fma(x, coeffs)
+}
+
+/// Evaluates `a + b x + c x² + d x³` with Estrin's scheme and fused multiply-adds.
+#[cfg(feature = "libm")]
+pub fn fused_cubic(x: f64, [a, b, c, d]: [f64; 4]) -> f64 {
+    polyeval::estrin_fma!(x; a, b, c, d)
+}
+
+#[cfg(all(test, feature = "libm"))]
+mod tests {
+    #[test]
+    fn test_fused() {
+        use super::{fused, fused_cubic};
+        // the product is not rounded before the subtraction: (1 + ε)(1 - ε) - 1 = -ε²
+        let e = 1.0 / (1u64 << 30) as f64;
+        assert_eq!(fused(1.0 + e, &[-1.0, 1.0 - e]), -e * e);
+        assert_eq!(fused_cubic(1.0 + e, [-1.0, 1.0 - e, 0.0, 0.0]), -e * e);
+        assert_eq!(fused(3.0, &[2.0, 3.0, 4.0]), 2.0 + 3.0 * (3.0 + 3.0 * 4.0));
+        assert_eq!(fused_cubic(3.0, [1.0, 2.0, 3.0, 4.0]), 142.0);
+    }
+}
@@ -0,0 +1,253 @@
+//! Evaluation with pluggable accumulation strategies.
+
+use core::mem;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+use crate::eft::{two_prod, two_sum};
+
+/// Strategy to accumulate the steps of [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// An accumulator represents the running value `acc` of the scheme, which starts at zero and
+/// is updated by [`step`](Accumulator::step) as `acc = acc * x + coeff` from the highest order
+/// coefficient down; [`finish`](Accumulator::finish) returns the value of the polynomial.
+/// Implementations may track any additional state, such as a running error estimate.
+///
+/// See [`horner_acc`].
+pub trait Accumulator<T> {
+    /// Returns the accumulator of the zero polynomial.
+    fn zero() -> Self;
+    /// Performs the step `acc = acc * x + coeff`.
+    fn step(&mut self, x: &T, coeff: &T);
+    /// Returns the accumulated value.
+    fn finish(self) -> T;
+}
+
+/// Accumulator performing the plain Horner's scheme, same as [`horner`](fn@crate::horner).
+#[derive(Clone, Copy, Debug)]
+pub struct Plain<T = f64> {
+    acc: T,
+}
+
+impl<T> Accumulator<T> for Plain<T>
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    fn zero() -> Self {
+        Plain { acc: T::zero() }
+    }
+
+    fn step(&mut self, x: &T, coeff: &T) {
+        self.acc = mem::replace(&mut self.acc, T::zero()) * x + coeff;
+    }
+
+    fn finish(self) -> T {
+        self.acc
+    }
+}
+
+/// Accumulator performing the compensated Horner's scheme.
+///
+/// The rounding errors of both the products and the sums are computed with error-free
+/// transformations and accumulated in a correction polynomial, which is added back at the end.
+/// The result is as accurate as if computed with twice the working precision.
+/// If either the value or the correction is not finite, the value is returned without
+/// the correction, as in [`horner_compensated`](fn@crate::horner_compensated).
+#[derive(Clone, Copy, Debug)]
+pub struct Compensated {
+    acc: f64,
+    correction: f64,
+}
+
+impl Accumulator<f64> for Compensated {
+    fn zero() -> Self {
+        Compensated {
+            acc: 0.0,
+            correction: 0.0,
+        }
+    }
+
+    fn step(&mut self, &x: &f64, &coeff: &f64) {
+        let (p, pi) = two_prod(self.acc, x);
+        let (s, sigma) = two_sum(p, coeff);
+        self.acc = s;
+        self.correction = self.correction * x + (pi + sigma);
+    }
+
+    fn finish(self) -> f64 {
+        if self.acc.is_finite() && self.correction.is_finite() {
+            self.acc + self.correction
+        } else {
+            self.acc
+        }
+    }
+}
+
+/// Accumulator performing the Horner's scheme with [Kahan summation](https://en.wikipedia.org/wiki/Kahan_summation_algorithm).
+///
+/// The rounding errors of the sums are recovered as in Kahan's compensated summation, while
+/// those of the products are not. This is cheaper than [`Compensated`], and is effective when
+/// the products are exact or nearly so, such as when `x` is a power of two.
+#[derive(Clone, Copy, Debug)]
+pub struct Kahan {
+    acc: f64,
+    compensation: f64,
+}
+
+impl Accumulator<f64> for Kahan {
+    fn zero() -> Self {
+        Kahan {
+            acc: 0.0,
+            compensation: 0.0,
+        }
+    }
+
+    fn step(&mut self, &x: &f64, &coeff: &f64) {
+        let p = self.acc * x;
+        let y = coeff - self.compensation * x;
+        let t = p + y;
+        self.compensation = (t - p) - y;
+        self.acc = t;
+    }
+
+    fn finish(self) -> f64 {
+        self.acc
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using a custom accumulation strategy.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The accumulator `A` determines how each step `acc * x + coeff` is carried out;
+/// the crate provides [`Plain`], [`Compensated`] and [`Kahan`], and users can implement
+/// [`Accumulator`] for their own strategies.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_acc, Compensated, Kahan, Plain};
+///
+/// let x = 7.0;
+/// let coeffs = [2.0, 3.0, 4.0];
+///
+/// assert_eq!(horner_acc::<Plain, _>(x, &coeffs), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// assert_eq!(horner_acc::<Plain<i32>, _>(7, &[2, 3, 4]), 2 + 7 * (3 + 7 * 4));
+///
+/// // the last two coefficients are lost to rounding with plain Horner
+/// let coeffs = [1e-16, 1e-16, 1.0];
+/// assert_eq!(horner_acc::<Plain, _>(1.0, &coeffs), 1.0);
+/// assert_eq!(horner_acc::<Kahan, _>(1.0, &coeffs), 1.0 + 2e-16);
+/// assert_eq!(horner_acc::<Compensated, _>(1.0, &coeffs), 1.0 + 2e-16);
+/// ```
+pub fn horner_acc<A: Accumulator<T>, T>(x: T, coeffs: &[T]) -> T {
+    let mut acc = A::zero();
+    for coeff in coeffs.iter().rev() {
+        acc.step(&x, coeff);
+    }
+    acc.finish()
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// compensating the rounding errors of the sums with [Kahan summation](https://en.wikipedia.org/wiki/Kahan_summation_algorithm).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// A running compensation term recovers the rounding error of every addition `acc * x + c`
+/// and feeds it into the next step, as in Kahan's compensated summation. Unlike
+/// [`horner_compensated`](fn@crate::horner_compensated), the rounding errors of the
+/// multiplications are not corrected: this is cheaper, and effective when the products are exact
+/// or nearly so (e.g. when `x` is a power of two) and the error comes from adding coefficients
+/// of very different magnitudes. Same as [`horner_acc`] with the [`Kahan`] accumulator.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_kahan};
+///
+/// // 1 + 10⁻¹⁶ + 10⁻¹⁶ + ... with ten tiny coefficients
+/// let mut coeffs = [1e-16; 11];
+/// coeffs[10] = 1.0;
+///
+/// assert_eq!(horner(1.0, &coeffs), 1.0);
+/// assert!((horner_kahan(1.0, &coeffs) - (1.0 + 1e-15)).abs() <= f64::EPSILON);
+/// ```
+pub fn horner_kahan(x: f64, coeffs: &[f64]) -> f64 {
+    horner_acc::<Kahan, _>(x, coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_kahan() {
+        use super::horner_kahan;
+        use crate::{horner, horner_compensated};
+        for x in -32..32 {
+            let x = x as f64 / 8.;
+            assert_eq!(horner_kahan(x, &[]), 0.);
+            assert_eq!(horner_kahan(x, &[3.]), 3.);
+            let coeffs = [1., -2., 3., -4., 5.];
+            assert_eq!(horner_kahan(x, &coeffs), horner(x, &coeffs));
+        }
+        // integer coefficients with a huge dynamic range: at x = 1 the products are exact,
+        // but plain Horner loses every unit added to 2⁵³
+        let mut coeffs = [1.; 1000];
+        coeffs[999] = 2f64.powi(53);
+        let exact = horner_compensated(1., &coeffs);
+        let plain = horner(1., &coeffs);
+        let kahan = horner_kahan(1., &coeffs);
+        assert_eq!(plain, 2f64.powi(53));
+        assert!((kahan - exact).abs() <= f64::EPSILON * exact.abs());
+        assert!((plain - exact).abs() > 900.);
+    }
+
+    #[test]
+    fn test_horner_acc() {
+        use super::{horner_acc, Compensated, Kahan, Plain};
+        use crate::compensated::horner_compensated;
+        use crate::horner;
+        for x in -32..32 {
+            let coeffs = [1, -2, 3, -4, 5];
+            assert_eq!(horner_acc::<Plain<_>, _>(x, &coeffs), horner(x, &coeffs));
+            let x = x as f64 / 8.;
+            let coeffs = [1., -2., 3., -4., 5.];
+            assert_eq!(horner_acc::<Plain, _>(x, &[]), 0.);
+            assert_eq!(horner_acc::<Plain, _>(x, &coeffs), horner(x, &coeffs));
+            assert_eq!(horner_acc::<Kahan, _>(x, &coeffs), horner(x, &coeffs));
+            let x = x + 1. / 3.;
+            let coeffs = [1. / 3., -2. / 7., 3. / 11., -4. / 13., 5. / 17.];
+            assert_eq!(horner_acc::<Plain, _>(x, &coeffs), horner(x, &coeffs));
+            assert_eq!(
+                horner_acc::<Compensated, _>(x, &coeffs),
+                horner_compensated(x, &coeffs)
+            );
+        }
+        // (x - 1)⁵ close to its root, where plain Horner cancels catastrophically
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        let x = 1. + 1. / 4096.;
+        assert_eq!(
+            horner_acc::<Compensated, _>(x, &coeffs),
+            (1. / 4096f64).powi(5)
+        );
+        assert_ne!(horner_acc::<Plain, _>(x, &coeffs), (1. / 4096f64).powi(5));
+        // the splitting of the operands overflows, though the product doesn't
+        assert_eq!(horner_acc::<Compensated, _>(1.5e308, &[0., 1.]), 1.5e308);
+        assert_eq!(
+            horner_acc::<Compensated, _>(f64::MAX, &[0., 2.]),
+            f64::INFINITY
+        );
+        // with exact products Kahan summation recovers the rounding errors of the sums
+        let mut coeffs = [1e-16; 10];
+        coeffs[9] = 1.;
+        let exact = horner_compensated(1., &coeffs);
+        assert_eq!(horner_acc::<Plain, _>(1., &coeffs), 1.);
+        assert_eq!(horner_acc::<Kahan, _>(1., &coeffs), exact);
+        assert_eq!(
+            horner_acc::<Kahan, _>(0.5, &coeffs),
+            horner_compensated(0.5, &coeffs)
+        );
+    }
+}
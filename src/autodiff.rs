@@ -0,0 +1,152 @@
+//! Automatic differentiation through the generic evaluation functions.
+
+use core::ops::{Add, Mul};
+use num_traits::{MulAdd, Zero};
+
+/// Hyper-dual number `re + e1 ε₁ + e2 ε₂ + e1e2 ε₁ε₂`, where `ε₁² = ε₂² = 0` and `ε₁ε₂ ≠ 0`.
+///
+/// Evaluating a function `f` at [`HyperDual::var(x)`](HyperDual::var) yields `f(x)` in `re`,
+/// the first derivative `f'(x)` in both `e1` and `e2`, and the second derivative `f''(x)`
+/// in `e1e2`, all exact up to rounding (there is no truncation error, unlike finite differences).
+///
+/// It implements the operator traits required by the generic functions of this crate,
+/// such as [`horner`](fn@crate::horner), and [`MulAdd`] for the `*_fma` macros.
+/// Notice that the coefficients must be hyper-dual numbers too, see [`HyperDual::constant`].
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, HyperDual};
+///
+/// // p(x) = 2 + 3x + 4x², p'(x) = 3 + 8x, p''(x) = 8
+/// let coeffs = [2.0, 3.0, 4.0].map(HyperDual::constant);
+/// let p = horner(HyperDual::var(7.0), &coeffs);
+///
+/// assert_eq!((p.re, p.e1, p.e1e2), (219.0, 59.0, 8.0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HyperDual {
+    /// Real part, i.e. the value.
+    pub re: f64,
+    /// Coefficient of `ε₁`, i.e. the first derivative.
+    pub e1: f64,
+    /// Coefficient of `ε₂`, i.e. the first derivative.
+    pub e2: f64,
+    /// Coefficient of `ε₁ε₂`, i.e. the second derivative.
+    pub e1e2: f64,
+}
+
+impl HyperDual {
+    /// Returns the constant `c`, whose derivatives are zero.
+    pub const fn constant(c: f64) -> Self {
+        HyperDual {
+            re: c,
+            e1: 0.0,
+            e2: 0.0,
+            e1e2: 0.0,
+        }
+    }
+
+    /// Returns the independent variable at `x`, whose first derivative is one.
+    pub const fn var(x: f64) -> Self {
+        HyperDual {
+            re: x,
+            e1: 1.0,
+            e2: 1.0,
+            e1e2: 0.0,
+        }
+    }
+}
+
+impl Add for HyperDual {
+    type Output = HyperDual;
+
+    fn add(self, other: HyperDual) -> HyperDual {
+        HyperDual {
+            re: self.re + other.re,
+            e1: self.e1 + other.e1,
+            e2: self.e2 + other.e2,
+            e1e2: self.e1e2 + other.e1e2,
+        }
+    }
+}
+
+impl Add<&HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    fn add(self, other: &HyperDual) -> HyperDual {
+        self + *other
+    }
+}
+
+impl Mul for HyperDual {
+    type Output = HyperDual;
+
+    fn mul(self, other: HyperDual) -> HyperDual {
+        HyperDual {
+            re: self.re * other.re,
+            e1: self.re * other.e1 + self.e1 * other.re,
+            e2: self.re * other.e2 + self.e2 * other.re,
+            e1e2: self.re * other.e1e2
+                + self.e1 * other.e2
+                + self.e2 * other.e1
+                + self.e1e2 * other.re,
+        }
+    }
+}
+
+impl Mul<&HyperDual> for HyperDual {
+    type Output = HyperDual;
+
+    fn mul(self, other: &HyperDual) -> HyperDual {
+        self * *other
+    }
+}
+
+impl Zero for HyperDual {
+    fn zero() -> Self {
+        HyperDual::constant(0.0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re == 0.0 && self.e1 == 0.0 && self.e2 == 0.0 && self.e1e2 == 0.0
+    }
+}
+
+impl MulAdd for HyperDual {
+    type Output = HyperDual;
+
+    fn mul_add(self, a: HyperDual, b: HyperDual) -> HyperDual {
+        self * a + b
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HyperDual;
+
+    #[test]
+    fn test_hyper_dual_horner() {
+        use crate::deriv::horner_deriv;
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5., -6.];
+        let d1 = [-2., 6., -12., 20., -30.];
+        let d2 = [6., -24., 60., -120.];
+        let coeffs_hd = coeffs.map(HyperDual::constant);
+        for x in -32..32 {
+            let x = x as f64 / 4.;
+            let p = horner(HyperDual::var(x), &coeffs_hd);
+            assert_eq!(p.re, horner(x, &coeffs));
+            assert_eq!(p.e1, horner(x, &d1));
+            assert_eq!(p.e2, horner(x, &d1));
+            assert_eq!(p.e1e2, horner(x, &d2));
+            assert_eq!((p.re, p.e1), horner_deriv(x, &coeffs));
+            let p = crate::horner_fma!(HyperDual::var(x); coeffs_hd[0], coeffs_hd[1], coeffs_hd[2]);
+            assert_eq!(
+                (p.re, p.e1, p.e1e2),
+                (horner(x, &coeffs[..3]), -2. + 6. * x, 6.)
+            );
+        }
+        assert_eq!(horner(HyperDual::var(3.), &[]), HyperDual::constant(0.));
+    }
+}
@@ -0,0 +1,283 @@
+//! Evaluation at many points at once.
+
+use alloc::collections::BTreeMap;
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::horner;
+
+/// Bin of a histogram which a value falls into.
+enum Bin {
+    Underflow,
+    Inside(usize),
+    Overflow,
+}
+
+/// Finds the bin of `y`, or `None` if `y` is NaN.
+///
+/// The bins are `[e₀, e₁), [e₁, e₂), ..., [eₙ₋₁, eₙ]`; the last one is closed.
+fn find_bin(edges: &[f64], y: f64) -> Option<Bin> {
+    if y.is_nan() {
+        return None;
+    }
+    let n = edges.len();
+    let i = edges.partition_point(|&e| e <= y);
+    Some(if i == 0 {
+        Bin::Underflow
+    } else if i < n {
+        Bin::Inside(i - 1)
+    } else if n >= 2 && y == edges[n - 1] {
+        Bin::Inside(n - 2)
+    } else {
+        Bin::Overflow
+    })
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at many points and count the values in the bins of a histogram.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The `bin_edges` must be sorted in ascending order; they delimit the bins
+/// `[e₀, e₁), [e₁, e₂), ..., [eₙ₋₁, eₙ]`, where the last one is closed.
+/// Returns the counts of the `bin_edges.len() - 1` bins.
+///
+/// Values outside the edges, as well as NaN values, are dropped;
+/// see [`horner_binned_with_overflow`] to count the values outside the edges.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_binned;
+///
+/// let xs = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+/// // values: 9, 4, 1, 0, 1, 4, 9, 16
+///
+/// assert_eq!(
+///     horner_binned(&xs, &[0.0, 0.0, 1.0], &[0.0, 1.0, 5.0, 10.0]),
+///     [1, 4, 2]
+/// );
+/// ```
+pub fn horner_binned(xs: &[f64], coeffs: &[f64], bin_edges: &[f64]) -> Vec<u64> {
+    let mut counts = vec![0; bin_edges.len().saturating_sub(1)];
+    for &x in xs {
+        if let Some(Bin::Inside(i)) = find_bin(bin_edges, horner(x, coeffs)) {
+            counts[i] += 1;
+        }
+    }
+    counts
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at many points and count the values in the bins of a histogram, including overflow bins.
+///
+/// Same as [`horner_binned`], but the returned counts have length `bin_edges.len() + 1`:
+/// the first one counts the values below `e₀` and the last one the values above `eₙ`.
+/// NaN values are still dropped.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_binned_with_overflow;
+///
+/// let xs = [-3.0, -2.0, -1.0, 0.0, 1.0, 2.0, 3.0, 4.0];
+/// // values: -9, -4, -1, 0, 1, 4, 9, 16
+///
+/// assert_eq!(
+///     horner_binned_with_overflow(&xs, &[0.0, 0.0, 1.0], &[0.0, 1.0, 5.0, 10.0]),
+///     [0, 1, 4, 2, 1]
+/// );
+/// ```
+pub fn horner_binned_with_overflow(xs: &[f64], coeffs: &[f64], bin_edges: &[f64]) -> Vec<u64> {
+    let n = bin_edges.len();
+    let mut counts = vec![0; n + 1];
+    for &x in xs {
+        match find_bin(bin_edges, horner(x, coeffs)) {
+            Some(Bin::Underflow) => counts[0] += 1,
+            Some(Bin::Inside(i)) => counts[i + 1] += 1,
+            Some(Bin::Overflow) => counts[n] += 1,
+            None => {}
+        }
+    }
+    counts
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at many points, writing the values into a reusable buffer.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The buffer `out` is cleared first, then it is filled with the values at the points `xs`,
+/// in the same order. Its capacity is reused, so calling this function in a loop with the same
+/// buffer allocates only when there are more points than ever before.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_multi_into;
+///
+/// let coeffs = [2.0, 3.0, 4.0];
+/// let mut out = Vec::new();
+///
+/// horner_multi_into(&[0.0, 1.0, 7.0], &coeffs, &mut out);
+/// assert_eq!(out, [2.0, 9.0, 219.0]);
+///
+/// horner_multi_into(&[-1.0], &coeffs, &mut out);
+/// assert_eq!(out, [3.0]);
+/// ```
+pub fn horner_multi_into(xs: &[f64], coeffs: &[f64], out: &mut Vec<f64>) {
+    out.clear();
+    out.extend(xs.iter().map(|&x| horner(x, coeffs)));
+}
+
+/// Evaluate many polynomials at many points, sharing the powers of each point.
+///
+/// The coefficients of every polynomial are listed from zeroth order to highest.
+///
+/// Returns one row per point, containing the value of every polynomial at that point,
+/// i.e. `result[i][j]` is the value of `polys[j]` at `xs[i]`.
+///
+/// For every distinct point the table of its powers `1, x, x², ...` is computed once,
+/// up to the highest degree among the polynomials, and then the value of every polynomial
+/// is obtained as a dot product of its coefficients with the table. Identical points (with the
+/// same bit pattern, so `0.0` and `-0.0` are distinct) are evaluated only once and their row is
+/// copied. This is efficient when many polynomials of high degree share few evaluation points.
+///
+/// The polynomials are evaluated as sums of monomials instead of with Horner's method,
+/// hence the results may differ from [`horner`](fn@crate::horner) by rounding.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_batch_points_cached;
+///
+/// let x = 2.0;
+/// let p: &[f64] = &[2.0, 3.0, 4.0];
+/// let q: &[f64] = &[1.0, 1.0];
+///
+/// assert_eq!(
+///     horner_batch_points_cached(&[x, -x, x], &[p, q]),
+///     [[24.0, 3.0], [12.0, -1.0], [24.0, 3.0]]
+/// );
+/// ```
+pub fn horner_batch_points_cached(xs: &[f64], polys: &[&[f64]]) -> Vec<Vec<f64>> {
+    let len = polys.iter().map(|coeffs| coeffs.len()).max().unwrap_or(0);
+    let mut powers = vec![0.0; len];
+    let mut seen: BTreeMap<u64, usize> = BTreeMap::new();
+    let mut rows: Vec<Vec<f64>> = Vec::with_capacity(xs.len());
+    for &x in xs {
+        if let Some(&i) = seen.get(&x.to_bits()) {
+            let row = rows[i].clone();
+            rows.push(row);
+            continue;
+        }
+        let mut xk = 1.0;
+        for power in powers.iter_mut() {
+            *power = xk;
+            xk *= x;
+        }
+        let row = polys
+            .iter()
+            .map(|coeffs| coeffs.iter().zip(&powers).map(|(c, p)| c * p).sum())
+            .collect();
+        seen.insert(x.to_bits(), rows.len());
+        rows.push(row);
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_binned() {
+        use super::horner_binned;
+        // identity polynomial, uniformly distributed outputs
+        let xs: Vec<f64> = (0..40).map(|k| k as f64 / 4.).collect();
+        let edges = [0., 2.5, 5., 7.5, 10.];
+        assert_eq!(horner_binned(&xs, &[0., 1.], &edges), [10, 10, 10, 10]);
+        assert_eq!(horner_binned(&xs, &[-5., 1.], &edges), [10, 10, 0, 0]);
+        assert_eq!(horner_binned(&xs, &[10., -1.], &edges), [9, 10, 10, 11]);
+        // the last bin is closed
+        assert_eq!(horner_binned(&[10.], &[0., 1.], &edges), [0, 0, 0, 1]);
+        assert_eq!(horner_binned(&[f64::NAN], &[0., 1.], &edges), [0, 0, 0, 0]);
+        assert!(horner_binned(&xs, &[0., 1.], &[1.]).is_empty());
+        assert!(horner_binned(&xs, &[0., 1.], &[]).is_empty());
+    }
+
+    #[test]
+    fn test_horner_binned_with_overflow() {
+        use super::horner_binned_with_overflow;
+        let xs: Vec<f64> = (0..40).map(|k| k as f64 / 4.).collect();
+        let edges = [0., 2.5, 5., 7.5, 10.];
+        assert_eq!(
+            horner_binned_with_overflow(&xs, &[0., 1.], &edges),
+            [0, 10, 10, 10, 10, 0]
+        );
+        assert_eq!(
+            horner_binned_with_overflow(&xs, &[-5., 1.], &edges),
+            [20, 10, 10, 0, 0, 0]
+        );
+        assert_eq!(
+            horner_binned_with_overflow(&xs, &[5., 1.], &edges),
+            [0, 0, 0, 10, 11, 19]
+        );
+        assert_eq!(
+            horner_binned_with_overflow(&[f64::NAN, 10.], &[0., 1.], &edges),
+            [0, 0, 0, 0, 1, 0]
+        );
+        assert_eq!(horner_binned_with_overflow(&xs, &[0., 1.], &[]), [40]);
+    }
+
+    #[test]
+    fn test_horner_multi_into() {
+        use super::horner_multi_into;
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4.];
+        let mut out = vec![f64::NAN; 3];
+        horner_multi_into(&[], &coeffs, &mut out);
+        assert!(out.is_empty());
+        let mut capacity = 0;
+        for n in [40, 10, 40, 0, 25] {
+            let xs: Vec<f64> = (0..n).map(|k| k as f64 / 8. - 2.).collect();
+            horner_multi_into(&xs, &coeffs, &mut out);
+            assert_eq!(out.len(), n);
+            for (&x, &y) in xs.iter().zip(&out) {
+                assert_eq!(y, horner(x, &coeffs));
+            }
+            // the capacity doesn't change after the first call with 40 points
+            if capacity == 0 {
+                capacity = out.capacity();
+            }
+            assert_eq!(out.capacity(), capacity);
+        }
+    }
+
+    #[test]
+    fn test_horner_batch_points_cached() {
+        use super::horner_batch_points_cached;
+        use crate::horner;
+        let polys: Vec<Vec<f64>> = (0..12)
+            .map(|n| (0..n).map(|k| ((k * 7 + n) % 11) as f64 - 5.).collect())
+            .collect();
+        let polys: Vec<&[f64]> = polys.iter().map(Vec::as_slice).collect();
+        // repeated points, with dyadic values so that the evaluations are exact
+        let xs: Vec<f64> = (0..40).map(|k| ((k * 5) % 9) as f64 / 4. - 1.).collect();
+        let rows = horner_batch_points_cached(&xs, &polys);
+        assert_eq!(rows.len(), xs.len());
+        for (&x, row) in xs.iter().zip(&rows) {
+            assert_eq!(row.len(), polys.len());
+            for (&coeffs, &y) in polys.iter().zip(row) {
+                assert_eq!(y, horner(x, coeffs));
+            }
+        }
+        assert!(horner_batch_points_cached(&[], &polys).is_empty());
+        assert_eq!(
+            horner_batch_points_cached(&xs, &[]),
+            vec![Vec::<f64>::new(); xs.len()]
+        );
+        let line: &[f64] = &[1., 1.];
+        let rows = horner_batch_points_cached(&[f64::NAN, 1., f64::NAN], &[line]);
+        assert!(rows[0][0].is_nan() && rows[2][0].is_nan());
+        assert_eq!(rows[1], [2.]);
+    }
+}
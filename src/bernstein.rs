@@ -0,0 +1,81 @@
+//! Evaluation of polynomials in the Bernstein basis.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul, Sub};
+use num_traits::{One, Zero};
+
+/// Evaluate a polynomial in the [Bernstein basis](https://en.wikipedia.org/wiki/Bernstein_polynomial)
+/// with [De Casteljau's algorithm](https://en.wikipedia.org/wiki/De_Casteljau%27s_algorithm).
+///
+/// Computes `∑ bᵢ (n choose i) tⁱ (1-t)ⁿ⁻ⁱ`, where `b₀, b₁, ..., bₙ` are the `control` points,
+/// that is the value at `t` of the Bézier curve with these control points.
+/// The control points are copied into a scratch buffer, where neighbouring points are
+/// interpolated linearly as `(1-t) bᵢ + t bᵢ₊₁` until a single point remains,
+/// with `O(n²)` operations.
+///
+/// For `0 <= t <= 1` every step is a convex combination, which makes the algorithm numerically
+/// stable, and the result is exactly `b₀` at `t = 0` and `bₙ` at `t = 1`.
+///
+/// Returns zero if there are no control points.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::de_casteljau;
+///
+/// let control = [1.0, 3.0, 2.0];
+/// // (1 - t)² b₀ + 2t (1 - t) b₁ + t² b₂
+/// assert_eq!(de_casteljau(0.5, &control), 0.25 * 1.0 + 0.5 * 3.0 + 0.25 * 2.0);
+///
+/// assert_eq!(de_casteljau(0.0, &control), 1.0);
+/// assert_eq!(de_casteljau(1.0, &control), 2.0);
+/// ```
+pub fn de_casteljau<T>(t: T, control: &[T]) -> T
+where
+    T: Clone + Zero + One + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    let s = T::one() - t.clone();
+    let mut scratch: Vec<T> = control.to_vec();
+    for len in (1..scratch.len()).rev() {
+        for i in 0..len {
+            scratch[i] = s.clone() * scratch[i].clone() + t.clone() * scratch[i + 1].clone();
+        }
+    }
+    scratch.into_iter().next().unwrap_or_else(T::zero)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_de_casteljau() {
+        use super::de_casteljau;
+        use num_traits::pow;
+        fn binomial(n: usize, k: usize) -> i64 {
+            (0..k).fold(1, |b, i| b * (n - i) as i64 / (i + 1) as i64)
+        }
+        let control = [3, -1, 4, 1, -5, 9, 2, -6, 5];
+        assert_eq!(de_casteljau(2, &[0; 0]), 0);
+        for n in 1..=8 {
+            let control = &control[..=n];
+            // exact with integers, also outside of [0, 1]
+            for t in -3..=3 {
+                let sum: i64 = (0..=n)
+                    .map(|i| binomial(n, i) * pow(t, i) * pow(1 - t, n - i) * control[i])
+                    .sum();
+                assert_eq!(de_casteljau(t, control), sum);
+            }
+            let control = control.iter().map(|&b| b as f64 / 4.).collect::<Vec<_>>();
+            for t in 0..=16 {
+                let t = t as f64 / 16.;
+                let sum: f64 = (0..=n)
+                    .map(|i| binomial(n, i) as f64 * pow(t, i) * pow(1. - t, n - i) * control[i])
+                    .sum();
+                assert!((de_casteljau(t, &control) - sum).abs() < 1e-14);
+            }
+            // endpoint interpolation
+            assert_eq!(de_casteljau(0., &control), control[0]);
+            assert_eq!(de_casteljau(1., &control), control[n]);
+        }
+        assert_eq!(de_casteljau(0.3, &[7.]), 7.);
+    }
+}
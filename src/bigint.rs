@@ -0,0 +1,85 @@
+//! Exact evaluation with arbitrary precision integers.
+
+use num_bigint::BigInt;
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// exactly, using arbitrary precision integers.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The coefficients are promoted to [`BigInt`] during the evaluation, hence the result never
+/// overflows; the point of evaluation can itself be arbitrarily large.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::BigInt;
+/// use polyeval::horner_bigint;
+///
+/// let x = BigInt::from(1_000_000_000_000_i64);
+///
+/// assert_eq!(
+///     horner_bigint(&x, &[2, 3, 4]).to_string(),
+///     "4000000000003000000000002"
+/// );
+/// ```
+pub fn horner_bigint(x: &BigInt, coeffs: &[i64]) -> BigInt {
+    coeffs
+        .iter()
+        .rfold(BigInt::zero(), |acc: BigInt, &c: &i64| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+
+    /// Coefficients of the rising factorial `x(x+1)...(x+n-1)`,
+    /// i.e. the unsigned Stirling numbers of the first kind.
+    fn rising_factorial_coeffs(n: usize) -> Vec<i64> {
+        let mut coeffs = vec![1];
+        for j in 0..n as i64 {
+            // multiply by (x + j)
+            let mut next = vec![0; coeffs.len() + 1];
+            for (k, &c) in coeffs.iter().enumerate() {
+                next[k] += j * c;
+                next[k + 1] += c;
+            }
+            coeffs = next;
+        }
+        coeffs
+    }
+
+    #[test]
+    fn test_horner_bigint() {
+        use super::horner_bigint;
+        for x in -32..32 {
+            let big = BigInt::from(x);
+            assert_eq!(horner_bigint(&big, &[]), BigInt::from(0));
+            assert_eq!(horner_bigint(&big, &[1]), BigInt::from(1));
+            assert_eq!(horner_bigint(&big, &[1, 2]), BigInt::from(1 + 2 * x));
+            assert_eq!(
+                horner_bigint(&big, &[1, 2, 3, 4, 5]),
+                BigInt::from(1 + x * (2 + x * (3 + x * (4 + x * 5))))
+            );
+        }
+    }
+
+    #[test]
+    fn test_horner_bigint_rising_factorial() {
+        use super::horner_bigint;
+        let n = 20;
+        let coeffs = rising_factorial_coeffs(n);
+
+        // at `x = 1` the rising factorial is `n!`
+        let factorial: BigInt = (1..=n as i64).map(BigInt::from).product();
+        assert_eq!(horner_bigint(&BigInt::from(1), &coeffs), factorial);
+        assert_eq!(factorial.to_string(), "2432902008176640000");
+
+        // at a huge point the result is astronomically large
+        let x = BigInt::from(10).pow(40) + 7;
+        let product: BigInt = (0..n as i64).map(|j| &x + j).product();
+        assert_eq!(horner_bigint(&x, &coeffs), product);
+        assert!(product.bits() > 2600);
+    }
+}
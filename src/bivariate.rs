@@ -0,0 +1,59 @@
+//! Evaluation of polynomials in two variables.
+
+use crate::horner;
+
+/// Evaluate a polynomial in two variables with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The polynomial is `p(x, y) = ∑ᵢⱼ cᵢⱼ xⁱ yʲ`, where `coeffs[i][j] = cᵢⱼ`: every `coeffs[i]`
+/// is the polynomial in `y`, listed from zeroth order to highest, which multiplies `xⁱ`.
+/// The rows may have different lengths.
+///
+/// It is evaluated as a polynomial in `x` whose coefficients are polynomials in `y`,
+/// both with Horner's method.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_2d;
+///
+/// // 1 + 2y + 3x + 4xy + 5x²
+/// let coeffs: [&[f64]; 3] = [&[1.0, 2.0], &[3.0, 4.0], &[5.0]];
+///
+/// let (x, y) = (2.0, 3.0);
+///
+/// assert_eq!(
+///     horner_2d(x, y, &coeffs),
+///     1.0 + 2.0 * y + 3.0 * x + 4.0 * x * y + 5.0 * x * x
+/// );
+/// ```
+pub fn horner_2d(x: f64, y: f64, coeffs: &[&[f64]]) -> f64 {
+    coeffs
+        .iter()
+        .rfold(0.0, |acc, row| acc * x + horner(y, row))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_2d() {
+        use super::horner_2d;
+        use crate::horner;
+        let coeffs: [&[f64]; 4] = [&[1., -2., 3.], &[], &[4., 5.], &[-6.]];
+        for x in -8..8 {
+            let x = x as f64;
+            for y in -8..8 {
+                let y = y as f64;
+                let expected =
+                    1. - 2. * y + 3. * y * y + 4. * x * x + 5. * x * x * y - 6. * x * x * x;
+                assert_eq!(horner_2d(x, y, &coeffs), expected);
+                assert_eq!(horner_2d(x, y, &[]), 0.);
+                // polynomials in a single variable
+                assert_eq!(horner_2d(x, y, &[&[1., 2., 3.]]), horner(y, &[1., 2., 3.]));
+                assert_eq!(
+                    horner_2d(x, y, &[&[1.], &[2.], &[3.]]),
+                    horner(x, &[1., 2., 3.])
+                );
+            }
+        }
+    }
+}
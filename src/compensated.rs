@@ -0,0 +1,441 @@
+//! Accurate floating point evaluation with error-free transformations.
+//!
+//! - <https://doi.org/10.1016/j.camwa.2006.12.020> (Graillat, Langlois, Louvet: Algorithms for accurate, validated and fast polynomial evaluation)
+
+use crate::eft::{add_toward_zero, mul_toward_zero, two_prod, two_prod_f32, two_sum, two_sum_f32};
+
+/// Runs the compensated Horner scheme, returning the plain Horner value
+/// and the accumulated correction separately.
+#[inline]
+fn horner_compensated_parts(x: f64, coeffs: &[f64]) -> (f64, f64) {
+    coeffs.iter().rfold((0.0, 0.0), |(s, c), &a| {
+        let (p, pi) = two_prod(s, x);
+        let (s, sigma) = two_sum(p, a);
+        (s, c * x + (pi + sigma))
+    })
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// compensating the rounding errors with error-free transformations.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The rounding errors of every product and sum are computed exactly with `TwoProd` (Dekker's
+/// algorithm, so no fused multiply-add is required) and `TwoSum`, and accumulated in a second
+/// Horner recurrence which is added to the result at the end. The value is as accurate as if it
+/// were computed with twice the working precision and then rounded, which matters close to the
+/// roots of ill-conditioned polynomials, at the cost of roughly ten times the operations of
+/// [`horner`](fn@crate::horner).
+///
+/// If the plain value overflows it is returned as is, without the correction. The same happens
+/// if the correction is not finite, because the splitting of Dekker's algorithm overflows for
+/// operands larger than about `2⁹⁹⁶` even when their product doesn't.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_compensated};
+///
+/// // (x - 1)⁵ close to its root
+/// let coeffs = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+/// let x: f64 = 1.0 + 1.0 / 3000.0;
+/// let exact = (x - 1.0).powi(5);
+///
+/// assert!((horner(x, &coeffs) - exact).abs() > 1e-3 * exact);
+/// assert!((horner_compensated(x, &coeffs) - exact).abs() < 1e-12 * exact);
+/// ```
+#[inline]
+pub fn horner_compensated(x: f64, coeffs: &[f64]) -> f64 {
+    let (s, c) = horner_compensated_parts(x, coeffs);
+    if s.is_finite() && c.is_finite() {
+        s + c
+    } else {
+        s
+    }
+}
+
+/// Same as [`horner_compensated`], in single precision.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_compensated_f32;
+///
+/// // (x - 1)³ close to its root
+/// let coeffs = [-1.0, 3.0, -3.0, 1.0];
+/// let x: f32 = 1.0 + 1.0 / 64.0;
+///
+/// assert_eq!(horner_compensated_f32(x, &coeffs), (x - 1.0).powi(3));
+/// ```
+#[inline]
+pub fn horner_compensated_f32(x: f32, coeffs: &[f32]) -> f32 {
+    let (s, c) = coeffs.iter().rfold((0.0, 0.0), |(s, c), &a| {
+        let (p, pi) = two_prod_f32(s, x);
+        let (s, sigma) = two_sum_f32(p, a);
+        (s, c * x + (pi + sigma))
+    });
+    if s.is_finite() && c.is_finite() {
+        s + c
+    } else {
+        s
+    }
+}
+
+/// Returns `true` if all the terms `cₖxᵏ` have the same sign (zeros are compatible with both),
+/// in which case no cancellation can happen.
+fn no_cancellation(x: f64, coeffs: &[f64]) -> bool {
+    if x == 0.0 {
+        return true;
+    }
+    let (mut positive, mut negative) = (true, true);
+    let mut flip = false;
+    for &c in coeffs {
+        let c = if flip { -c } else { c };
+        positive &= c >= 0.0;
+        negative &= c <= 0.0;
+        flip ^= x < 0.0;
+    }
+    positive || negative
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// switching to the compensated scheme only when cancellation is possible.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// When all the terms `cₖxᵏ` have the same sign no cancellation can happen, and plain Horner is
+/// already accurate; this is the case when `x >= 0` and all the coefficients share the same sign,
+/// or when `x < 0` and the signs of the coefficients alternate. Zero coefficients are compatible
+/// with both signs. The check costs one comparison per coefficient, without any multiplication.
+///
+/// Otherwise the polynomial is evaluated with the compensated Horner scheme, which is as accurate
+/// as if it were computed with twice the working precision and then rounded.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_adaptive;
+///
+/// assert_eq!(horner_adaptive(7.0, &[2.0, 3.0, 4.0]), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+///
+/// // (x - 1)⁵ close to its root
+/// let coeffs = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+/// let x: f64 = 1.0 + 1e-3;
+/// let exact = (x - 1.0).powi(5);
+/// assert!((horner_adaptive(x, &coeffs) - exact).abs() < 1e-15 * exact);
+/// ```
+pub fn horner_adaptive(x: f64, coeffs: &[f64]) -> f64 {
+    if no_cancellation(x, coeffs) {
+        coeffs.iter().rfold(0.0, |acc, &c| acc * x + c)
+    } else {
+        horner_compensated(x, coeffs)
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// compensating only the rounding errors of the last step.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// All the steps are performed as in [`horner`](fn@crate::horner) except for the last one,
+/// `s * x + c₀`, whose rounding errors are computed with error-free transformations and added back,
+/// so that it is almost correctly rounded. This costs only a few more operations than plain Horner.
+///
+/// The benefit is narrow: it helps when the constant term is the largest in magnitude and cancels
+/// with the rest of the polynomial (e.g. `x ≈ 0` with `c₀ = -c₁x` or `p(x) = 1 - cos(x)`-like
+/// approximations), where the last addition would otherwise absorb the small accumulated value.
+/// The rounding errors of the previous steps are not compensated, so in general the result is
+/// only marginally more accurate on average, and occasionally slightly less accurate, than plain
+/// Horner: for accurate results see [`horner_adaptive`].
+///
+/// As in [`horner_compensated`], a correction which is not finite is dropped.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_last_compensated;
+///
+/// assert_eq!(horner_last_compensated(7.0, &[2.0, 3.0, 4.0]), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+///
+/// // 0.1 * 10 - 1 rounds to zero
+/// assert_eq!(0.1 * 10.0 - 1.0, 0.0);
+/// assert_eq!(horner_last_compensated(10.0, &[-1.0, 0.1]), 5.551115123125783e-17);
+/// ```
+pub fn horner_last_compensated(x: f64, coeffs: &[f64]) -> f64 {
+    match coeffs.split_first() {
+        None => 0.0,
+        Some((&c0, rest)) => {
+            let s = rest.iter().rfold(0.0, |acc, &c| acc * x + c);
+            let (p, pi) = two_prod(s, x);
+            let (s, sigma) = two_sum(p, c0);
+            let c = pi + sigma;
+            if s.is_finite() && c.is_finite() {
+                s + c
+            } else {
+                s
+            }
+        }
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// reporting whether the result is likely exact.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the same value as [`horner`](fn@crate::horner) together with a flag, which is `true`
+/// when the plain result agrees bit-for-bit with the one computed at the same time by the
+/// compensated Horner scheme, i.e. when the accumulated rounding errors don't affect the result.
+///
+/// The flag is a heuristic: agreement is strong evidence that the result is exact, but not an
+/// absolute proof, because the accumulated correction may be too small to change the rounded result,
+/// or rounding errors of opposite sign may cancel out in it.
+/// Disagreement, on the other hand, indicates that the result is inexact.
+/// The flag is also `false` when the correction is not finite, e.g. because the splitting of
+/// Dekker's algorithm overflows for operands larger than about `2⁹⁹⁶`, since then nothing can
+/// be said about the result.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_exactness;
+///
+/// assert_eq!(horner_exactness(7.0, &[2.0, 3.0, 4.0]), (2.0 + 7.0 * (3.0 + 7.0 * 4.0), true));
+///
+/// // (x - 1)³ close to its root
+/// let (_, exact) = horner_exactness(1.0 + 1e-4, &[-1.0, 3.0, -3.0, 1.0]);
+/// assert!(!exact);
+/// ```
+pub fn horner_exactness(x: f64, coeffs: &[f64]) -> (f64, bool) {
+    let (s, c) = horner_compensated_parts(x, coeffs);
+    (s, (s + c).to_bits() == s.to_bits())
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// in two rounding modes.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `(nearest, toward_zero)`, where:
+/// - `nearest` is computed with the default rounding of every operation to the nearest
+///   representable value (ties to even), and is the same as [`horner`](fn@crate::horner);
+/// - `toward_zero` is computed rounding the result of every multiplication and addition toward zero
+///   (i.e. truncating it), emulated in software with error-free transformations.
+///
+/// The two results coincide when no rounding occurs, whereas a large gap between them
+/// flags that the evaluation is sensitive to rounding.
+/// In the subnormal range the emulated products may be rounded to nearest instead.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_round_modes;
+///
+/// assert_eq!(horner_round_modes(7.0, &[2.0, 3.0, 4.0]), (219.0, 219.0));
+///
+/// let (nearest, toward_zero) = horner_round_modes(0.1, &[0.1, 0.2, 0.3]);
+/// assert!(toward_zero < nearest);
+/// ```
+pub fn horner_round_modes(x: f64, coeffs: &[f64]) -> (f64, f64) {
+    coeffs
+        .iter()
+        .rfold((0.0, 0.0), |(nearest, toward_zero), &c| {
+            (
+                nearest * x + c,
+                add_toward_zero(mul_toward_zero(toward_zero, x), c),
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_compensated() {
+        use super::horner_compensated;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_compensated(x, &[]), 0.);
+            assert_eq!(horner_compensated(x, &[1.]), 1.);
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_compensated(x, &coeffs), horner(x, &coeffs));
+        }
+        // (x - 1)^5 close to its root
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        let x = 1. + 1. / 1024.;
+        assert_eq!(horner_compensated(x, &coeffs), (x - 1.).powi(5));
+        let x: f64 = 1. + 1. / 3000.;
+        let exact = (x - 1.).powi(5);
+        let plain = horner(x, &coeffs);
+        let compensated = horner_compensated(x, &coeffs);
+        assert!((compensated - exact).abs() <= 1e-12 * exact);
+        assert!((plain - exact).abs() > 1e3 * (compensated - exact).abs());
+        assert_eq!(horner_compensated(f64::MAX, &[0., 2.]), f64::INFINITY);
+        // the splitting of the operands overflows, though the product doesn't
+        assert_eq!(horner_compensated(1.5e308, &[0., 1.]), 1.5e308);
+        assert_eq!(horner_compensated(0.5, &[0., f64::MAX]), 0.5 * f64::MAX);
+        assert_eq!(
+            horner_compensated(f64::MAX, &[-1., 1.]),
+            horner(f64::MAX, &[-1., 1.])
+        );
+    }
+
+    #[test]
+    fn test_horner_compensated_f32() {
+        use super::horner_compensated_f32;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f32;
+            assert_eq!(horner_compensated_f32(x, &[]), 0.);
+            assert_eq!(horner_compensated_f32(x, &[1.]), 1.);
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_compensated_f32(x, &coeffs), horner(x, &coeffs));
+        }
+        // (x - 1)^5 close to its root, against a reference in double precision,
+        // which is exact for these inputs
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        let (mut plain_err, mut compensated_err) = (0f64, 0f64);
+        for k in 1..32 {
+            let x = 1. + k as f32 / 32.;
+            let exact = (x as f64 - 1.).powi(5);
+            let relative = |y: f32| ((y as f64 - exact) / exact).abs();
+            let compensated = relative(horner_compensated_f32(x, &coeffs));
+            assert!(compensated <= f32::EPSILON as f64);
+            compensated_err = compensated_err.max(compensated);
+            plain_err = plain_err.max(relative(horner(x, &coeffs)));
+        }
+        assert!(plain_err > 1e3 * compensated_err);
+        assert_eq!(horner_compensated_f32(f32::MAX, &[0., 2.]), f32::INFINITY);
+        assert_eq!(horner_compensated_f32(3e38, &[0., 1.]), 3e38);
+        assert_eq!(horner_compensated_f32(0.5, &[0., f32::MAX]), 0.5 * f32::MAX);
+    }
+
+    #[test]
+    fn test_no_cancellation() {
+        use super::no_cancellation;
+        assert!(no_cancellation(2., &[]));
+        assert!(no_cancellation(2., &[1., 2., 0., 3.]));
+        assert!(no_cancellation(2., &[-1., -2., 0., -3.]));
+        assert!(no_cancellation(0., &[-1., 2., -3.]));
+        assert!(no_cancellation(-2., &[1., -2., 0., -3.]));
+        assert!(no_cancellation(-2., &[-1., 2., 0., 3.]));
+        assert!(!no_cancellation(2., &[1., -2., 3.]));
+        assert!(!no_cancellation(-2., &[1., 2., 3.]));
+    }
+
+    #[test]
+    fn test_horner_adaptive() {
+        use super::{horner_adaptive, horner_compensated};
+        use crate::horner;
+        // plain branch
+        let coeffs = [0.1, 0.2, 0.3, 0.4, 0.5];
+        for x in 0..32 {
+            let x = x as f64 / 10.;
+            assert_eq!(horner_adaptive(x, &coeffs), horner(x, &coeffs));
+            let alternating = [0.1, -0.2, 0.3, -0.4, 0.5];
+            assert_eq!(horner_adaptive(-x, &alternating), horner(-x, &alternating));
+        }
+        // compensated branch
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        for k in 1..32 {
+            let x = 1. + k as f64 / 1024.;
+            let exact = (x - 1.).powi(5);
+            assert_eq!(horner_adaptive(x, &coeffs), horner_compensated(x, &coeffs));
+            assert!((horner_adaptive(x, &coeffs) - exact).abs() <= f64::EPSILON * exact);
+        }
+    }
+
+    #[test]
+    fn test_horner_exactness() {
+        use super::horner_exactness;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_exactness(x, &[]), (0., true));
+            assert_eq!(horner_exactness(x, &[1.]), (1., true));
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_exactness(x, &coeffs), (horner(x, &coeffs), true));
+            let x = x / 8.;
+            assert_eq!(horner_exactness(x, &coeffs), (horner(x, &coeffs), true));
+        }
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        let x = 1. + 1. / 3000.;
+        assert_eq!(horner_exactness(x, &coeffs), (horner(x, &coeffs), false));
+        let coeffs = [-1., 3., -3., 1.];
+        for k in 1..32 {
+            let x = 1. + k as f64 * 1e-4;
+            assert!(!horner_exactness(x, &coeffs).1);
+        }
+        // the correction is not available
+        assert_eq!(horner_exactness(1.5e308, &[0., 1.]), (1.5e308, false));
+        assert_eq!(
+            horner_exactness(f64::MAX, &[0., 2.]),
+            (f64::INFINITY, false)
+        );
+    }
+
+    #[test]
+    fn test_horner_round_modes() {
+        use super::horner_round_modes;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_round_modes(x, &[]), (0., 0.));
+            assert_eq!(horner_round_modes(x, &[1.]), (1., 1.));
+            let coeffs = [1., 2., 3., 4., 5.];
+            let y = horner(x, &coeffs);
+            assert_eq!(horner_round_modes(x, &coeffs), (y, y));
+            let x = x / 16.;
+            let y = horner(x, &coeffs);
+            assert_eq!(horner_round_modes(x, &coeffs), (y, y));
+        }
+        let coeffs = [0.1, 0.2, 0.3, 0.4, 0.5];
+        for x in 1..32 {
+            let x = x as f64 / 10.;
+            let (nearest, toward_zero) = horner_round_modes(x, &coeffs);
+            assert_eq!(nearest, horner(x, &coeffs));
+            assert!(toward_zero <= nearest);
+            assert!(nearest - toward_zero <= 8. * f64::EPSILON * nearest);
+            let (nearest, toward_zero) = horner_round_modes(x, &coeffs.map(|c| -c));
+            assert!(toward_zero >= nearest);
+        }
+    }
+
+    #[test]
+    fn test_horner_last_compensated() {
+        use super::{horner_compensated, horner_last_compensated};
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_last_compensated(x, &[]), 0.);
+            assert_eq!(horner_last_compensated(x, &[1.]), 1.);
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_last_compensated(x, &coeffs), horner(x, &coeffs));
+        }
+        // pseudo-random inputs, compared with the compensated scheme as a reference:
+        // a single evaluation can be slightly worse, but not on aggregate
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2. - 1.
+        };
+        let (mut total_plain, mut total_last) = (0., 0.);
+        for _ in 0..1000 {
+            let x = random();
+            let coeffs: [f64; 6] = core::array::from_fn(|_| random());
+            let exact = horner_compensated(x, &coeffs);
+            let plain = (horner(x, &coeffs) - exact).abs();
+            let last = (horner_last_compensated(x, &coeffs) - exact).abs();
+            total_plain += plain;
+            total_last += last;
+        }
+        assert!(total_last <= total_plain);
+        // the splitting of the operands overflows, though the product doesn't
+        assert_eq!(horner_last_compensated(1.5e308, &[0., 1.]), 1.5e308);
+        assert_eq!(horner_last_compensated(f64::MAX, &[0., 2.]), f64::INFINITY);
+    }
+}
@@ -0,0 +1,306 @@
+//! Evaluation at complex points.
+
+#[cfg(all(
+    feature = "num-complex",
+    feature = "alloc",
+    any(feature = "std", feature = "libm")
+))]
+use alloc::vec::Vec;
+#[cfg(feature = "num-complex")]
+use num_complex::Complex;
+
+/// Evaluate a polynomial with real coefficients at the complex point `re + i im`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the real and imaginary parts of the result. Instead of running
+/// [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method) with complex arithmetic,
+/// the polynomial is divided by the real quadratic `(y - x)(y - x̄) = y² - r y + s`,
+/// where `r = 2 re` and `s = re² + im²`, with the recurrence
+///
+/// ```text
+/// (a, b) ← (b + r a, c - s a)
+/// ```
+///
+/// for every coefficient `c` from the highest order down, and then `p(x) = a x + b`.
+/// Every step takes only two real multiplications and two additions,
+/// versus the four multiplications and four additions of a complex multiply-add.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_real_at_complex;
+///
+/// // 1 + x² at x = 2i
+/// assert_eq!(horner_real_at_complex(0.0, 2.0, &[1.0, 0.0, 1.0]), (-3.0, 0.0));
+/// // 2 + 3x at x = 1 + i
+/// assert_eq!(horner_real_at_complex(1.0, 1.0, &[2.0, 3.0]), (5.0, 3.0));
+/// ```
+pub fn horner_real_at_complex(re: f64, im: f64, coeffs: &[f64]) -> (f64, f64) {
+    let (&last, rest) = match coeffs.split_last() {
+        Some(split) => split,
+        None => return (0.0, 0.0),
+    };
+    let (&second, rest) = match rest.split_last() {
+        Some(split) => split,
+        None => return (last, 0.0),
+    };
+    let r = 2.0 * re;
+    let s = re * re + im * im;
+    let (a, b) = rest
+        .iter()
+        .rfold((last, second), |(a, b), &c| (b + r * a, c - s * a));
+    (a * re + b, a * im)
+}
+
+/// Evaluate the frequency response of a polynomial with real coefficients
+/// with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// For every angular frequency `ω` in `omegas` the polynomial is evaluated on the unit circle,
+/// at `x = exp(iω)`, i.e. the result is `c₀ + c₁exp(iω) + c₂exp(2iω) + ... + cₙexp(niω)`.
+///
+/// Note the positive sign in the exponent: with the usual convention of signal processing, the
+/// frequency response `H(exp(iω)) = c₀ + c₁exp(-iω) + ... + cₙexp(-niω)` of a FIR filter with taps `cₖ`
+/// is obtained passing `-ω`, or taking the complex conjugate of the result.
+///
+/// # Examples
+///
+/// ```
+/// use core::f64::consts::PI;
+/// use num_complex::Complex;
+/// use polyeval::freq_response;
+///
+/// let response = freq_response(&[2.0, 3.0], &[0.0, PI]);
+///
+/// assert_eq!(response[0], Complex::new(5.0, 0.0));
+/// assert!((response[1] - Complex::new(-1.0, 0.0)).norm() < 1e-15);
+/// ```
+#[cfg(all(
+    feature = "num-complex",
+    feature = "alloc",
+    any(feature = "std", feature = "libm")
+))]
+pub fn freq_response(coeffs: &[f64], omegas: &[f64]) -> Vec<Complex<f64>> {
+    omegas
+        .iter()
+        .map(|&omega| {
+            let x = Complex::cis(omega);
+            coeffs
+                .iter()
+                .rfold(Complex::new(0.0, 0.0), |acc, &c| acc * x + c)
+        })
+        .collect()
+}
+
+/// Multiplies two Gaussian integers, returning `None` on overflow.
+#[cfg(feature = "num-complex")]
+fn checked_mul(a: Complex<i64>, b: Complex<i64>) -> Option<Complex<i64>> {
+    let re =
+        a.re.checked_mul(b.re)?
+            .checked_sub(a.im.checked_mul(b.im)?)?;
+    let im =
+        a.re.checked_mul(b.im)?
+            .checked_add(a.im.checked_mul(b.re)?)?;
+    Some(Complex::new(re, im))
+}
+
+/// Adds two Gaussian integers, returning `None` on overflow.
+#[cfg(feature = "num-complex")]
+fn checked_add(a: Complex<i64>, b: Complex<i64>) -> Option<Complex<i64>> {
+    Some(Complex::new(
+        a.re.checked_add(b.re)?,
+        a.im.checked_add(b.im)?,
+    ))
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// exactly over the [Gaussian integers](https://en.wikipedia.org/wiki/Gaussian_integer).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every operation on the real and imaginary parts is checked,
+/// and the result is `None` if any of them overflows.
+///
+/// Notice that [`horner`](fn@crate::horner) itself works with `Complex<i64>`, but it panics
+/// on overflow in debug builds and wraps around silently in release builds.
+///
+/// # Examples
+///
+/// ```
+/// use num_complex::Complex;
+/// use polyeval::horner_gaussian;
+///
+/// let i = Complex::new(0, 1);
+/// let x = Complex::new(1, 1);
+///
+/// // 1 + i x + x² = 1 + (i - 1) + 2i = 3i
+/// assert_eq!(
+///     horner_gaussian(x, &[Complex::new(1, 0), i, Complex::new(1, 0)]),
+///     Some(Complex::new(0, 3))
+/// );
+/// ```
+#[cfg(feature = "num-complex")]
+pub fn horner_gaussian(x: Complex<i64>, coeffs: &[Complex<i64>]) -> Option<Complex<i64>> {
+    coeffs.iter().try_rfold(Complex::new(0, 0), |acc, &c| {
+        checked_add(checked_mul(acc, x)?, c)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "num-complex")]
+    use num_complex::Complex;
+
+    #[cfg(all(
+        feature = "num-complex",
+        feature = "alloc",
+        any(feature = "std", feature = "libm")
+    ))]
+    fn assert_close(a: Complex<f64>, b: Complex<f64>) {
+        assert!(
+            (a - b).norm() <= 1e-12 * b.norm().max(1.),
+            "{a} is not close to {b}"
+        );
+    }
+
+    #[test]
+    fn test_horner_real_at_complex() {
+        use super::horner_real_at_complex;
+        use crate::horner;
+        for re in -8..8 {
+            for im in -8..8 {
+                let (re, im) = (re as f64 / 4., im as f64 / 4.);
+                assert_eq!(horner_real_at_complex(re, im, &[]), (0., 0.));
+                assert_eq!(horner_real_at_complex(re, im, &[3.]), (3., 0.));
+                assert_eq!(
+                    horner_real_at_complex(re, im, &[3., 2.]),
+                    (3. + 2. * re, 2. * im)
+                );
+                // 1 - x + 2x² with x² = re² - im² + 2i re im
+                assert_eq!(
+                    horner_real_at_complex(re, im, &[1., -1., 2.]),
+                    (1. - re + 2. * (re * re - im * im), -im + 4. * re * im)
+                );
+                // on the real axis it agrees with plain Horner
+                let coeffs = [1., -2., 3., -4., 5., -6.];
+                assert_eq!(
+                    horner_real_at_complex(re, 0., &coeffs),
+                    (horner(re, &coeffs), 0.)
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "num-complex", any(feature = "std", feature = "libm")))]
+    fn test_horner_real_at_complex_num_complex() {
+        use super::horner_real_at_complex;
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5., -6., 7., -8.];
+        let complex_coeffs = coeffs.map(|c| Complex::new(c, 0.));
+        for re in -8..8 {
+            for im in -8..8 {
+                let x = Complex::new(re as f64 / 4., im as f64 / 4.);
+                let y = horner(x, &complex_coeffs);
+                let (re, im) = horner_real_at_complex(x.re, x.im, &coeffs);
+                assert!((Complex::new(re, im) - y).norm() <= 1e-12 * y.norm().max(1.));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "num-complex",
+        feature = "alloc",
+        any(feature = "std", feature = "libm")
+    ))]
+    fn test_freq_response() {
+        use super::freq_response;
+        use core::f64::consts::PI;
+        let omegas: Vec<f64> = (-32..=32).map(|k| k as f64 * PI / 32.).collect();
+        assert_eq!(freq_response(&[1.], &[]), []);
+        assert!(freq_response(&[], &omegas)
+            .iter()
+            .all(|&h| h == Complex::new(0., 0.)));
+        // first order filter 1 + a exp(iω)
+        for a in [-0.9, -0.5, 0.25, 1.] {
+            let response = freq_response(&[1., a], &omegas);
+            for (&omega, &h) in omegas.iter().zip(&response) {
+                assert_close(h, Complex::new(1. + a * omega.cos(), a * omega.sin()));
+                let gain = (1. + 2. * a * omega.cos() + a * a).sqrt();
+                assert!((h.norm() - gain).abs() <= 1e-12);
+            }
+        }
+        // moving average (1 + exp(iω)) / 2 = exp(iω/2) cos(ω/2)
+        let response = freq_response(&[0.5, 0.5], &omegas);
+        for (&omega, &h) in omegas.iter().zip(&response) {
+            assert_close(h, Complex::cis(omega / 2.) * (omega / 2.).cos());
+        }
+        // periodicity and conjugate symmetry of real coefficients
+        let coeffs = [1., -2., 3., -4., 5.];
+        let response = freq_response(&coeffs, &omegas);
+        for (k, &h) in response.iter().enumerate() {
+            assert_close(h, freq_response(&coeffs, &[omegas[k] + 2. * PI])[0]);
+            assert_close(h.conj(), response[response.len() - 1 - k]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-complex")]
+    fn test_horner_gaussian() {
+        use super::horner_gaussian;
+        use crate::horner;
+        let x = Complex::new(1, 1);
+        // (1 + i)ᵏ = 2^(k/2) exp(ikπ/4)
+        let powers = [
+            (1, 0),
+            (1, 1),
+            (0, 2),
+            (-2, 2),
+            (-4, 0),
+            (-4, -4),
+            (0, -8),
+            (8, -8),
+            (16, 0),
+        ];
+        for (k, &(re, im)) in powers.iter().enumerate() {
+            let mut monomial = [Complex::new(0, 0); 9];
+            monomial[k] = Complex::new(1, 0);
+            assert_eq!(horner_gaussian(x, &monomial), Some(Complex::new(re, im)));
+        }
+        // 1 + x + ... + x⁸
+        let ones = [Complex::new(1, 0); 9];
+        assert_eq!(horner_gaussian(x, &ones), Some(Complex::new(16, -15)));
+        let coeffs = [Complex::new(3, -1), Complex::new(0, 2), Complex::new(-1, 0)];
+        for re in -8..8 {
+            for im in -8..8 {
+                let x = Complex::new(re, im);
+                let y = Complex::new(3, -1) + Complex::new(0, 2) * x - x * x;
+                assert_eq!(horner_gaussian(x, &coeffs), Some(y));
+                assert_eq!(horner(x, &coeffs), y);
+            }
+        }
+        assert_eq!(horner_gaussian(x, &[]), Some(Complex::new(0, 0)));
+        // (1 + i)¹²⁸ = 2⁶⁴ overflows
+        let mut coeffs = [Complex::new(0, 0); 129];
+        coeffs[128] = Complex::new(1, 0);
+        assert_eq!(horner_gaussian(x, &coeffs[..127]), Some(Complex::new(0, 0)));
+        assert_eq!(horner_gaussian(x, &coeffs), None);
+        assert_eq!(
+            horner_gaussian(
+                Complex::new(0, 0),
+                &[Complex::new(i64::MAX, 0), Complex::new(1, 1)]
+            ),
+            Some(Complex::new(i64::MAX, 0))
+        );
+        assert_eq!(
+            horner_gaussian(
+                Complex::new(1, 0),
+                &[Complex::new(i64::MAX, 0), Complex::new(1, 1)]
+            ),
+            None
+        );
+    }
+}
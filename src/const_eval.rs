@@ -0,0 +1,92 @@
+//! Evaluation in `const` contexts.
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// in a `const` context.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// This is the same as [`horner`](fn@crate::horner) for `f64`, but it is a `const fn`,
+/// so it can be used to compute constants and lookup tables at compile time,
+/// e.g. together with [`fill_coeffs!`](macro@crate::fill_coeffs).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_f64;
+///
+/// const P: [f64; 3] = [2.0, 3.0, 4.0];
+/// const Y: f64 = horner_f64(7.0, &P);
+///
+/// assert_eq!(Y, 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// ```
+pub const fn horner_f64(x: f64, coeffs: &[f64]) -> f64 {
+    let mut acc = 0.0;
+    let mut i = coeffs.len();
+    while i > 0 {
+        i -= 1;
+        acc = acc * x + coeffs[i];
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// in a `const` context.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner_f64`], in single precision.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_f32;
+///
+/// const Y: f32 = horner_f32(7.0, &[2.0, 3.0, 4.0]);
+///
+/// assert_eq!(Y, 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// ```
+pub const fn horner_f32(x: f32, coeffs: &[f32]) -> f32 {
+    let mut acc = 0.0;
+    let mut i = coeffs.len();
+    while i > 0 {
+        i -= 1;
+        acc = acc * x + coeffs[i];
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_f64() {
+        use super::horner_f64;
+        use crate::horner;
+        const EMPTY: f64 = horner_f64(2., &[]);
+        const Y: f64 = horner_f64(0.5, &[1., -2., 3., -4.]);
+        // the values are used in array lengths, so they must be known at compile time
+        const N: usize = horner_f64(3., &[1., 2., 3.]) as usize;
+        assert_eq!(EMPTY, 0.);
+        assert_eq!(Y, 1. + 0.5 * (-2. + 0.5 * (3. + 0.5 * -4.)));
+        assert_eq!([0u8; N].len(), 34);
+        for x in -32..32 {
+            let x = x as f64 / 4.;
+            let coeffs = [1., -2., 3., -4., 5.];
+            assert_eq!(horner_f64(x, &coeffs), horner(x, &coeffs));
+        }
+    }
+
+    #[test]
+    fn test_horner_f32() {
+        use super::horner_f32;
+        use crate::horner;
+        const Y: f32 = horner_f32(0.5, &[1., -2., 3., -4.]);
+        const N: usize = horner_f32(3., &[1., 2., 3.]) as usize;
+        assert_eq!(Y, 1. + 0.5 * (-2. + 0.5 * (3. + 0.5 * -4.)));
+        assert_eq!([0u8; N].len(), 34);
+        for x in -32..32 {
+            let x = x as f32 / 4.;
+            let coeffs = [1., -2., 3., -4., 5.];
+            assert_eq!(horner_f32(x, &coeffs), horner(x, &coeffs));
+        }
+    }
+}
@@ -0,0 +1,742 @@
+//! Evaluation of derivatives and antiderivatives.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::ops::Div;
+use core::ops::{Add, Mul};
+#[cfg(feature = "alloc")]
+use num_traits::FromPrimitive;
+use num_traits::Zero;
+
+/// Evaluate a polynomial and its derivative with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `(p(x), p'(x))`, computed in a single pass with two accumulators updated for every
+/// coefficient `c`, from the highest order down, as `d ← d x + p` and `p ← p x + c`.
+/// This is cheaper than evaluating `p` and the differentiated coefficients separately,
+/// e.g. at every step of Newton's method. The derivative of a constant (or empty) polynomial is zero.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_deriv;
+///
+/// // p(x) = 2 + 3x + 4x², p'(x) = 3 + 8x
+/// assert_eq!(horner_deriv(7, &[2, 3, 4]), (219, 59));
+/// assert_eq!(horner_deriv(7, &[2]), (2, 0));
+/// ```
+#[track_caller]
+pub fn horner_deriv<T>(x: T, coeffs: &[T]) -> (T, T)
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let (mut p, mut d) = (T::zero(), T::zero());
+    for c in coeffs.iter().rev() {
+        d = d * &x + &p;
+        p = p * &x + c;
+    }
+    (p, d)
+}
+
+/// Compute the Taylor coefficients of a polynomial at a point with repeated
+/// [synthetic division](https://en.wikipedia.org/wiki/Synthetic_division).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Fills `out` with the coefficients of the same polynomial expanded in powers of `(y - x)`,
+/// i.e. the scaled derivatives `p(x), p'(x), p''(x) / 2!, …`, listed from zeroth order to highest.
+/// This is a Taylor shift by `x`, and it takes `O(n²)` operations.
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_derivatives};
+///
+/// // p(y) = 2 + 3y + 4y² = 219 + 59(y - 7) + 4(y - 7)²
+/// let coeffs = [2, 3, 4];
+/// let mut out = [0; 3];
+/// horner_derivatives(7, &coeffs, &mut out);
+/// assert_eq!(out, [219, 59, 4]);
+///
+/// let y = 10;
+/// assert_eq!(horner(y - 7, &out), horner(y, &coeffs));
+/// ```
+#[track_caller]
+pub fn horner_derivatives<T>(x: T, coeffs: &[T], out: &mut [T])
+where
+    T: Clone,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    assert_eq!(
+        coeffs.len(),
+        out.len(),
+        "the coefficients and the output buffer must have the same length"
+    );
+    let n = coeffs.len();
+    out.clone_from_slice(coeffs);
+    // the i-th division by (y - x) leaves the remainder in out[i]
+    for i in 0..n {
+        for j in (i..n.saturating_sub(1)).rev() {
+            out[j] = out[j].clone() + &(out[j + 1].clone() * &x);
+        }
+    }
+}
+
+/// Compute the coefficients of the derivative of a polynomial.
+///
+/// The coefficients are listed from zeroth order to highest, both for the polynomial and for
+/// the returned derivative, which has one fewer: the coefficient of order `k - 1` of `p'`
+/// is `k * coeffs[k]`. The derivative of a constant (or empty) polynomial is the empty vector,
+/// which has the value zero everywhere.
+///
+/// To evaluate the derivative at a few points, [`horner_deriv`] avoids the allocation.
+///
+/// # Panics
+///
+/// Panics if some order `k` can't be converted into `T` with [`FromPrimitive::from_usize`].
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::derivative;
+///
+/// // p(x) = 2 + 3x + 4x², p'(x) = 3 + 8x
+/// assert_eq!(derivative(&[2, 3, 4]), [3, 8]);
+/// assert!(derivative(&[2.0]).is_empty());
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn derivative<T>(coeffs: &[T]) -> Vec<T>
+where
+    T: Clone + Mul<Output = T> + FromPrimitive,
+{
+    coeffs
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(k, c)| {
+            let k =
+                T::from_usize(k).expect("the order is not representable in the coefficient type");
+            k * c.clone()
+        })
+        .collect()
+}
+
+/// Compute the coefficients of an antiderivative of a polynomial.
+///
+/// The coefficients are listed from zeroth order to highest, both for the polynomial and for
+/// the returned antiderivative, which has one more: its zeroth order coefficient is the
+/// integration `constant`, and the coefficient of order `k + 1` is `coeffs[k] / (k + 1)`.
+/// This is the inverse of [`derivative`], up to the constant.
+///
+/// For integer types the divisions truncate, so the result is exact only when every `k + 1`
+/// divides the coefficient of order `k`.
+///
+/// # Panics
+///
+/// Panics if some `k + 1` can't be converted into `T` with [`FromPrimitive::from_usize`].
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::integrate;
+///
+/// // p(x) = 3 + 8x, ∫p = 2 + 3x + 4x²
+/// assert_eq!(integrate(&[3.0, 8.0], 2.0), [2.0, 3.0, 4.0]);
+/// assert_eq!(integrate(&[], 5.0), [5.0]);
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn integrate<T>(coeffs: &[T], constant: T) -> Vec<T>
+where
+    T: Clone + Div<Output = T> + FromPrimitive,
+{
+    let mut out = Vec::with_capacity(coeffs.len() + 1);
+    out.push(constant);
+    for (k, c) in coeffs.iter().enumerate() {
+        let k =
+            T::from_usize(k + 1).expect("the order is not representable in the coefficient type");
+        out.push(c.clone() / k);
+    }
+    out
+}
+
+/// Compute the definite integral of a polynomial over an interval with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `∫ₐᵇ p = F(b) - F(a)`, where `F` is the antiderivative with the coefficients
+/// `coeffs[k] / (k + 1)` and zero constant term. Instead of subtracting the two values of `F`,
+/// which cancel catastrophically when the interval is short compared to its position,
+/// the result is computed as `(b - a) Q`, where `Q = (F(b) - F(a)) / (b - a)` is the
+/// divided difference of `F`: [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// is run on `F` at `a`, while its partial values are accumulated at `b` into `Q`.
+/// Hence the result has a small relative error even for tiny intervals, and no allocation
+/// is required for the coefficients of `F`. Swapping `a` and `b` changes the sign of the result,
+/// up to rounding.
+#[cfg_attr(
+    feature = "alloc",
+    doc = "The coefficients of `F` itself are returned by [`integrate`] with `constant = 0`."
+)]
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::integrate_definite;
+///
+/// // ∫₀² (3 + 8x) dx = 6 + 16
+/// assert_eq!(integrate_definite(&[3.0, 8.0], 0.0, 2.0), 22.0);
+///
+/// // ∫ x² over [10⁸, 10⁸ + 10⁻⁸] is about 10⁸, with all the digits correct
+/// let a = 1e8;
+/// let b = a + 1e-8;
+/// let exact = (b - a) * (a * a + a * b + b * b) / 3.0;
+/// assert!((integrate_definite(&[0.0, 0.0, 1.0], a, b) - exact).abs() <= 1e-15 * exact);
+/// ```
+pub fn integrate_definite(coeffs: &[f64], a: f64, b: f64) -> f64 {
+    // fa = F(a) without the constant term, q = (F(b) - F(a)) / (b - a), for the leading terms
+    let (mut fa, mut q) = (0.0, 0.0);
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        q = q * b + fa;
+        fa = fa * a + c / (k + 1) as f64;
+    }
+    // the last step, for the coefficient of order zero of F, which cancels out
+    q = q * b + fa;
+    (b - a) * q
+}
+
+/// Value, first and second derivative of a polynomial at a point.
+///
+/// See [`horner_jet2`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Jet2 {
+    /// Value `p(x)`.
+    pub value: f64,
+    /// First derivative `p'(x)`.
+    pub d1: f64,
+    /// Second derivative `p''(x)`.
+    pub d2: f64,
+}
+
+/// Evaluate a polynomial and its first two derivatives with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The three values are computed in a single pass, updating three accumulators for every
+/// coefficient `c`, from the highest order down, as
+///
+/// ```text
+/// d₂ ← d₂ x + 2 d₁
+/// d₁ ← d₁ x + p
+/// p  ← p x + c
+/// ```
+///
+/// where the right-hand sides use the values before the update. The field `d2` is the true
+/// second derivative `p''(x)`, not the Taylor coefficient `p''(x) / 2`. This is what Halley's
+/// method and trust-region solvers need at every step.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_jet2, Jet2};
+///
+/// // p(x) = 2 + 3x + 4x², p'(x) = 3 + 8x, p''(x) = 8
+/// assert_eq!(
+///     horner_jet2(7.0, &[2.0, 3.0, 4.0]),
+///     Jet2 { value: 219.0, d1: 59.0, d2: 8.0 }
+/// );
+/// ```
+pub fn horner_jet2(x: f64, coeffs: &[f64]) -> Jet2 {
+    let (value, d1, d2) = coeffs.iter().rfold((0.0, 0.0, 0.0), |(p, d1, d2), &c| {
+        (p * x + c, d1 * x + p, d2 * x + 2.0 * d1)
+    });
+    Jet2 { value, d1, d2 }
+}
+
+/// Estimate a step `h >= 0` such that a polynomial doesn't change sign on `[x, x + h]`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The first order approximation `p(x + h) ≈ p(x) + h p'(x)` vanishes at distance `|p(x) / p'(x)|`
+/// from `x` (the Newton step); the returned step is half of this distance, to leave some margin
+/// for the curvature. It is zero at the roots of `p` and infinite where `p'` vanishes (and `p`
+/// doesn't), including for constant polynomials.
+///
+/// This is a heuristic meant for adaptive sampling and curve tracing, **not** a rigorous bound:
+/// if `p` bends enough the sign may still change within the step.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::safe_step;
+///
+/// // p(x) = (x - 1)(x - 4) = 4 - 5x + x²
+/// let coeffs = [4.0, -5.0, 1.0];
+///
+/// // p(2) = -2, p'(2) = -1
+/// assert_eq!(safe_step(2.0, &coeffs), 1.0);
+/// assert_eq!(safe_step(4.0, &coeffs), 0.0);
+/// ```
+pub fn safe_step(x: f64, coeffs: &[f64]) -> f64 {
+    let (p, d) = horner_deriv(x, coeffs);
+    if p == 0.0 {
+        0.0
+    } else {
+        0.5 * (p / d).abs()
+    }
+}
+
+/// Perform one step of [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method)
+/// to solve `p(x) = y`, evaluating the polynomial and its derivative with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the next iterate `x - (p(x) - y) / p'(x)`, or NaN if `p'(x) == 0`.
+/// Both `p(x)` and `p'(x)` are computed in a single pass over the coefficients.
+///
+/// Iterating this step computes the inverse `p⁻¹(y)`, but deciding when to stop and whether the
+/// iteration converges at all is the caller's responsibility: it converges quadratically close to
+/// a simple solution, but it may diverge or cycle from a poor starting point, and it converges only
+/// linearly to a multiple solution.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_newton_step;
+///
+/// // p(x) = x², so that p⁻¹(2) = √2
+/// let coeffs = [0.0, 0.0, 1.0];
+///
+/// let mut x = 1.0;
+/// for _ in 0..6 {
+///     x = horner_newton_step(x, &coeffs, 2.0);
+/// }
+/// assert!((x - 2.0_f64.sqrt()).abs() < 1e-15);
+///
+/// assert!(horner_newton_step(0.0, &coeffs, 2.0).is_nan());
+/// ```
+pub fn horner_newton_step(x: f64, coeffs: &[f64], y: f64) -> f64 {
+    let (p, d) = horner_deriv(x, coeffs);
+    if d == 0.0 {
+        f64::NAN
+    } else {
+        x - (p - y) / d
+    }
+}
+
+/// Refine an approximate root of a polynomial with a fixed number of steps of
+/// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Applies `iters` times the update `x ← x - p(x) / p'(x)` starting from `x0`, evaluating
+/// `p(x)` and `p'(x)` in a single pass with [`horner_deriv`]. The iteration stops early,
+/// returning the current iterate, if it lands exactly on a root or on a critical point, where
+/// the update is undefined.
+///
+/// This is meant to polish a good initial guess, e.g. one found by bisection or by an
+/// eigenvalue solver, close to a simple root, where the convergence is quadratic.
+/// See [`newton_refine_tol`] to stop as soon as the iterates settle.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::newton_refine;
+///
+/// // (x - 2)(x - 3) = 6 - 5x + x²
+/// let coeffs = [6.0, -5.0, 1.0];
+///
+/// let x = newton_refine(&coeffs, 2.9, 6);
+/// assert!((x - 3.0).abs() < 1e-15);
+/// assert_eq!(newton_refine(&coeffs, 2.9, 0), 2.9);
+/// ```
+pub fn newton_refine(coeffs: &[f64], x0: f64, iters: usize) -> f64 {
+    let mut x = x0;
+    for _ in 0..iters {
+        let (p, d) = horner_deriv(x, coeffs);
+        if p == 0.0 || d == 0.0 {
+            break;
+        }
+        x -= p / d;
+    }
+    x
+}
+
+/// Refine an approximate root of a polynomial with
+/// [Newton's method](https://en.wikipedia.org/wiki/Newton%27s_method), until the steps become small.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`newton_refine`], except that the iteration also stops after the first step
+/// `|p(x) / p'(x)| <= tol`, having performed at most `max_iters` steps. Close to a simple root
+/// the step bounds the error of the previous iterate, and the quadratic convergence makes the
+/// returned iterate much more accurate than that.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::newton_refine_tol;
+///
+/// // (x - 2)(x - 3) = 6 - 5x + x²
+/// let coeffs = [6.0, -5.0, 1.0];
+///
+/// let x = newton_refine_tol(&coeffs, 2.1, 1e-12, 100);
+/// assert!((x - 2.0).abs() < 1e-15);
+/// ```
+pub fn newton_refine_tol(coeffs: &[f64], x0: f64, tol: f64, max_iters: usize) -> f64 {
+    let mut x = x0;
+    for _ in 0..max_iters {
+        let (p, d) = horner_deriv(x, coeffs);
+        if p == 0.0 || d == 0.0 {
+            break;
+        }
+        let step = p / d;
+        x -= step;
+        if step.abs() <= tol {
+            break;
+        }
+    }
+    x
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_deriv() {
+        use super::horner_deriv;
+        use crate::horner;
+        for x in -16..16 {
+            assert_eq!(horner_deriv(x, &[]), (0, 0));
+            assert_eq!(horner_deriv(x, &[3]), (3, 0));
+            assert_eq!(horner_deriv(x, &[3, 2]), (3 + 2 * x, 2));
+            let coeffs = [1, 2, 3, 4, 5];
+            assert_eq!(
+                horner_deriv(x, &coeffs),
+                (horner(x, &coeffs), horner(x, &[2, 6, 12, 20]))
+            );
+            // p(x) = x⁶ - 3x⁴ + x - 7, p'(x) = 6x⁵ - 12x³ + 1
+            assert_eq!(
+                horner_deriv(x, &[-7, 1, 0, 0, -3, 0, 1]),
+                (
+                    horner(x, &[-7, 1, 0, 0, -3, 0, 1]),
+                    horner(x, &[1, 0, 0, -12, 0, 6])
+                )
+            );
+        }
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            // p(x) = 0.5 - x² + 0.25x³, p'(x) = -2x + 0.75x²
+            let (p, d) = horner_deriv(x, &[0.5, 0., -1., 0.25]);
+            assert_eq!(p, 0.5 - x * x + 0.25 * x * x * x);
+            assert_eq!(d, -2. * x + 0.75 * x * x);
+        }
+    }
+
+    #[test]
+    fn test_horner_derivatives() {
+        use super::{horner_deriv, horner_derivatives};
+        use crate::horner;
+        let mut out = [0; 8];
+        horner_derivatives(3, &[], &mut []);
+        horner_derivatives(3, &[5], &mut out[..1]);
+        assert_eq!(out[0], 5);
+        // (y + 1)³ = ((y - 1) + 2)³
+        horner_derivatives(1, &[1, 3, 3, 1], &mut out[..4]);
+        assert_eq!(&out[..4], &[8, 12, 6, 1]);
+        let coeffs = [-7, 1, 0, 0, -3, 0, 1, 2];
+        for a in -8..8 {
+            horner_derivatives(a, &coeffs, &mut out);
+            let (p, d) = horner_deriv(a, &coeffs);
+            assert_eq!((out[0], out[1]), (p, d));
+            for y in -8..8 {
+                assert_eq!(horner(y - a, &out), horner(y, &coeffs));
+            }
+        }
+        for a in -8..8 {
+            let a = a as f64 / 4.;
+            let coeffs = [0.5, 0., -1., 0.25];
+            let mut out = [0.; 4];
+            horner_derivatives(a, &coeffs, &mut out);
+            for y in -8..8 {
+                let y = y as f64 / 4.;
+                assert_eq!(horner(y - a, &out), horner(y, &coeffs));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn test_horner_derivatives_short() {
+        use super::horner_derivatives;
+        horner_derivatives(1, &[1, 2, 3], &mut [0; 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn test_horner_derivatives_long() {
+        use super::horner_derivatives;
+        horner_derivatives(1, &[1, 2, 3], &mut [0; 4]);
+    }
+
+    #[test]
+    fn test_safe_step() {
+        use super::safe_step;
+        use crate::horner;
+        // p(x) = (x + 2)(x - 1)(x - 3), derivative bounded away from zero near the roots
+        let coeffs = [6., -5., -2., 1.];
+        let roots = [-2., 1., 3.];
+        for &root in &roots {
+            assert_eq!(safe_step(root, &coeffs), 0.);
+        }
+        for k in -64..=80 {
+            let x = k as f64 / 16.;
+            if roots.contains(&x) {
+                continue;
+            }
+            let h = safe_step(x, &coeffs);
+            assert!(h > 0., "non-positive step {h} at {x}");
+            // close to the simple roots the first order estimate is reliable
+            if roots.iter().all(|&r| (x - r).abs() > 0.5) {
+                continue;
+            }
+            let sign = horner(x, &coeffs).signum();
+            for j in 0..=16 {
+                let y = horner(x + h * j as f64 / 16., &coeffs);
+                assert_eq!(y.signum(), sign, "sign change at {x} + {h} * {j} / 16");
+            }
+        }
+        assert_eq!(safe_step(1., &[2.]), f64::INFINITY);
+        assert_eq!(safe_step(0., &[1., 0., 1.]), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_horner_newton_step() {
+        use super::horner_newton_step;
+        use crate::horner;
+        // p(x) = x³ + x is strictly increasing, so Newton's method converges from anywhere
+        let coeffs = [0., 1., 0., 1.];
+        for k in -8..=8 {
+            let solution = k as f64 / 2.;
+            let y = horner(solution, &coeffs);
+            let mut x = 0.;
+            for _ in 0..64 {
+                x = horner_newton_step(x, &coeffs, y);
+            }
+            assert!((x - solution).abs() <= 1e-15 * solution.abs());
+            assert_eq!(horner_newton_step(solution, &coeffs, y), solution);
+        }
+        // a linear polynomial is inverted in a single step
+        assert_eq!(horner_newton_step(5., &[1., 2.], 7.), 3.);
+        assert!(horner_newton_step(1., &[], 1.).is_nan());
+        assert!(horner_newton_step(1., &[3.], 1.).is_nan());
+    }
+
+    #[test]
+    fn test_newton_refine() {
+        use super::newton_refine;
+        use crate::horner;
+        // (x - 2)(x - 3)(x + 1) = 6 + x - 4x² + x³
+        let coeffs = [6., 1., -4., 1.];
+        for root in [-1., 2., 3.] {
+            for delta in [-0.1, -0.01, 0.001, 0.05] {
+                let x = newton_refine(&coeffs, root + delta, 8);
+                assert!((x - root).abs() <= 16. * f64::EPSILON, "{x} != {root}");
+            }
+            assert_eq!(newton_refine(&coeffs, root, 8), root);
+        }
+        // each step is the one of `horner_newton_step`
+        let mut x = 3.3;
+        for iters in 0..4 {
+            assert_eq!(newton_refine(&coeffs, 3.3, iters), x);
+            x = super::horner_newton_step(x, &coeffs, 0.);
+        }
+        // a critical point stops the iteration: p'(0) = 0 for x² - 1
+        assert_eq!(newton_refine(&[-1., 0., 1.], 0., 8), 0.);
+        assert_eq!(newton_refine(&[3.], 1., 8), 1.);
+        assert_eq!(
+            horner(newton_refine(&[-1., 0., 1.], 0.5, 16), &[-1., 0., 1.]),
+            0.
+        );
+    }
+
+    #[test]
+    fn test_newton_refine_tol() {
+        use super::{newton_refine, newton_refine_tol};
+        // (x - 2)(x - 3) = 6 - 5x + x²
+        let coeffs = [6., -5., 1.];
+        for (root, x0) in [(2., 1.9), (2., 2.3), (3., 2.8), (3., 4.)] {
+            let x = newton_refine_tol(&coeffs, x0, 1e-10, 100);
+            assert!((x - root).abs() <= 16. * f64::EPSILON, "{x} != {root}");
+        }
+        // stops early: a huge tolerance accepts the first step
+        assert_eq!(
+            newton_refine_tol(&coeffs, 2.8, 1., 100),
+            newton_refine(&coeffs, 2.8, 1)
+        );
+        // and never performs more than `max_iters` steps
+        for iters in 0..4 {
+            assert_eq!(
+                newton_refine_tol(&coeffs, 4., 0., iters),
+                newton_refine(&coeffs, 4., iters)
+            );
+        }
+        // linear convergence to a double root still terminates
+        let x = newton_refine_tol(&[4., -4., 1.], 3., 1e-9, 1000);
+        assert!((x - 2.).abs() < 1e-7);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_derivative() {
+        use super::{derivative, horner_deriv};
+        use crate::horner;
+        assert_eq!(derivative::<i32>(&[]), [0; 0]);
+        assert_eq!(derivative(&[5]), [0; 0]);
+        assert_eq!(derivative(&[5, 7]), [7]);
+        // p(x) = x⁶ - 3x⁴ + x - 7, p'(x) = 6x⁵ - 12x³ + 1
+        assert_eq!(derivative(&[-7, 1, 0, 0, -3, 0, 1]), [1, 0, 0, -12, 0, 6]);
+        let coeffs = [1., -2., 3., -4., 5., -6.];
+        let d = derivative(&coeffs);
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            assert_eq!(horner(x, &d), horner_deriv(x, &coeffs).1);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "the order is not representable in the coefficient type")]
+    fn test_derivative_unrepresentable_order() {
+        use super::derivative;
+        derivative(&[0u8; 300]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_integrate() {
+        use super::{derivative, integrate};
+        assert_eq!(integrate(&[], 3), [3]);
+        assert_eq!(integrate(&[4], 0), [0, 4]);
+        // ∫(6x⁵ - 12x³ + 1) = x⁶ - 3x⁴ + x - 7
+        assert_eq!(
+            integrate(&[1, 0, 0, -12, 0, 6], -7),
+            [-7, 1, 0, 0, -3, 0, 1]
+        );
+        // the derivative recovers the polynomial, whatever the constant
+        let coeffs: [f64; 8] = [1., -2., 3., -4., 5., -6., 7., -8.];
+        for constant in [0., 1.5, -100.] {
+            let antiderivative = integrate(&coeffs, constant);
+            assert_eq!(antiderivative[0], constant);
+            let d = derivative(&antiderivative);
+            assert_eq!(d.len(), coeffs.len());
+            for (a, b) in d.iter().zip(&coeffs) {
+                assert!((a - b).abs() <= 2. * f64::EPSILON * b.abs());
+            }
+        }
+        // exact for multiples of powers of two
+        let coeffs = [2., 4., 6., 8.];
+        assert_eq!(derivative(&integrate(&coeffs, 9.)), coeffs);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    #[should_panic(expected = "the order is not representable in the coefficient type")]
+    fn test_integrate_unrepresentable_order() {
+        use super::integrate;
+        integrate(&[0u8; 300], 0);
+    }
+
+    #[test]
+    fn test_integrate_definite() {
+        use super::integrate_definite;
+        assert_eq!(integrate_definite(&[], 1., 2.), 0.);
+        assert_eq!(integrate_definite(&[3.], 1., 2.5), 4.5);
+        // monomials: ∫ₐᵇ xᵏ = (bᵏ⁺¹ - aᵏ⁺¹) / (k + 1)
+        for k in 0..12 {
+            let mut coeffs = [0.; 12];
+            coeffs[k] = 1.;
+            for (a, b) in [(0., 1.), (-1., 1.), (0.5, 2.), (-3., -1.25), (2f64, -2f64)] {
+                let exact = (b.powi(k as i32 + 1) - a.powi(k as i32 + 1)) / (k + 1) as f64;
+                let y = integrate_definite(&coeffs, a, b);
+                assert!(
+                    (y - exact).abs() <= 1e-13 * exact.abs().max(1.),
+                    "{y} != {exact}"
+                );
+                let swapped = integrate_definite(&coeffs, b, a);
+                assert!((swapped + y).abs() <= 1e-13 * exact.abs().max(1.));
+            }
+        }
+        // short intervals far from the origin keep a small relative error:
+        // ∫ₐᵇ x³ = (b - a)(a³ + a²b + ab² + b³) / 4
+        for a in [1e3, -1e5, 1e8f64] {
+            let b = a + a.abs() * 1e-12;
+            let exact = (b - a) * (a * a * a + a * a * b + a * b * b + b * b * b) / 4.;
+            let y = integrate_definite(&[0., 0., 0., 1.], a, b);
+            assert!(
+                (y - exact).abs() <= 4. * f64::EPSILON * exact.abs(),
+                "{y} != {exact}"
+            );
+        }
+        assert_eq!(integrate_definite(&[1., 2., 3.], 1.5, 1.5), 0.);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_integrate_definite_antiderivative() {
+        use super::{integrate, integrate_definite};
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5., -6.];
+        let antiderivative = integrate(&coeffs, 0.);
+        for a in -8..8 {
+            for b in -8..8 {
+                let (a, b) = (a as f64 / 4., b as f64 / 4.);
+                let expected = horner(b, &antiderivative) - horner(a, &antiderivative);
+                let y = integrate_definite(&coeffs, a, b);
+                assert!((y - expected).abs() <= 1e-12 * expected.abs().max(1.));
+            }
+        }
+    }
+
+    #[test]
+    fn test_horner_jet2() {
+        use super::{horner_deriv, horner_jet2, Jet2};
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5., -6.];
+        // p'' computed by hand
+        let second = [6., -24., 60., -120.];
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            assert_eq!(horner_jet2(x, &[]), Jet2::default());
+            assert_eq!(
+                horner_jet2(x, &[3.]),
+                Jet2 {
+                    value: 3.,
+                    d1: 0.,
+                    d2: 0.
+                }
+            );
+            assert_eq!(
+                horner_jet2(x, &[3., 2.]),
+                Jet2 {
+                    value: 3. + 2. * x,
+                    d1: 2.,
+                    d2: 0.
+                }
+            );
+            let jet = horner_jet2(x, &coeffs);
+            let (value, d1) = horner_deriv(x, &coeffs);
+            assert_eq!(jet.value, horner(x, &coeffs));
+            assert_eq!((jet.value, jet.d1), (value, d1));
+            assert_eq!(jet.d2, horner(x, &second));
+        }
+    }
+}
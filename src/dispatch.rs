@@ -0,0 +1,116 @@
+//! Evaluation with instructions selected at runtime.
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod x86 {
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const SUPPORTED: u8 = 1;
+    const UNSUPPORTED: u8 = 2;
+
+    /// Whether the CPU supports FMA, detected on the first call to [`has_fma`].
+    static FMA: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    /// Returns `true` if the CPU supports the FMA instructions.
+    #[inline]
+    pub(super) fn has_fma() -> bool {
+        match FMA.load(Ordering::Relaxed) {
+            SUPPORTED => true,
+            UNSUPPORTED => false,
+            _ => {
+                let supported = std::is_x86_feature_detected!("fma");
+                // a race only repeats the detection, which gives the same answer
+                FMA.store(
+                    if supported { SUPPORTED } else { UNSUPPORTED },
+                    Ordering::Relaxed,
+                );
+                supported
+            }
+        }
+    }
+
+    /// Same as [`horner_fma`](fn@crate::horner_fma), compiled with the FMA instructions enabled.
+    ///
+    /// # Safety
+    ///
+    /// The CPU must support FMA, see [`has_fma`].
+    #[target_feature(enable = "fma")]
+    pub(super) unsafe fn horner_fma(x: f64, coeffs: &[f64]) -> f64 {
+        match coeffs.split_last() {
+            None => 0.0,
+            Some((&last, rest)) => rest.iter().rev().fold(last, |acc, &c| acc.mul_add(x, c)),
+        }
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using "fused multiply-add" instructions if the CPU supports them.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// On `x86` and `x86_64`, the support for FMA is detected at runtime with
+/// [`is_x86_feature_detected!`](std::arch::is_x86_feature_detected) on the first call,
+/// and the answer is cached in a static. If FMA is available the result is the same as
+/// [`horner_fma`](fn@crate::horner_fma), computed with the hardware instruction even if the crate
+/// is compiled for a baseline CPU; otherwise it is the same as [`horner`](fn@crate::horner), with
+/// a separate multiplication and addition. On other architectures this is the same as
+/// [`horner`](fn@crate::horner).
+///
+/// This matters for binaries built without the `fma` target feature (e.g. without
+/// `-C target-cpu=native`), where [`horner_fma`](fn@crate::horner_fma) calls the `fma` function
+/// of the C library at every step, emulated in software on CPUs lacking the instruction.
+/// Compare them with `RUSTFLAGS="-C target-cpu=x86-64" cargo bench --bench dispatch`.
+///
+/// The results of the two paths usually differ in the last bits, so don't rely on this function
+/// being reproducible across machines.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_auto;
+///
+/// let x = 7.0;
+///
+/// assert_eq!(horner_auto(x, &[]), 0.0);
+/// assert_eq!(horner_auto(x, &[2.0, 3.0, 4.0]), 2.0 + x * (3.0 + x * 4.0));
+/// ```
+pub fn horner_auto(x: f64, coeffs: &[f64]) -> f64 {
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if x86::has_fma() {
+        // SAFETY: the support for FMA has just been checked
+        return unsafe { x86::horner_fma(x, coeffs) };
+    }
+    crate::horner(x, coeffs)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_auto() {
+        use super::horner_auto;
+        use crate::{horner, horner_fma};
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        let fma = super::x86::has_fma();
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        let fma = false;
+        // the Taylor polynomial of the exponential is well-conditioned on [0, 1]
+        let mut coeffs = [1.; 12];
+        for k in 1..coeffs.len() {
+            coeffs[k] = coeffs[k - 1] / k as f64;
+        }
+        for x in 0..=64 {
+            let x = x as f64 / 64.;
+            let auto = horner_auto(x, &coeffs);
+            let fused = horner_fma(x, &coeffs);
+            let separate = horner(x, &coeffs);
+            assert_eq!(auto, if fma { fused } else { separate });
+            let ulp = f64::from_bits(separate.to_bits() + 1) - separate;
+            assert!((fused - separate).abs() <= ulp);
+        }
+        assert_eq!(horner_auto(2., &[]), 0.);
+        assert_eq!(horner_auto(2., &[3.]), 3.);
+        // both paths propagate non-finite values
+        assert!(horner_auto(f64::NAN, &[1., 1.]).is_nan());
+        assert_eq!(horner_auto(2., &[1., f64::INFINITY]), f64::INFINITY);
+    }
+}
@@ -0,0 +1,85 @@
+//! Human-readable rendering of polynomials.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+/// Render a polynomial in human-readable form, such as `"4x^2 + 3x + 2"`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The terms are written from highest order to lowest, skipping the zero coefficients.
+/// Unit coefficients are omitted except in the constant term, `x^1` is written as `x`,
+/// and negative coefficients are rendered as subtractions.
+/// The zero polynomial (including the empty one) is rendered as `"0"`.
+///
+/// This is meant for error messages and logs, not for parsing.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_debug_string;
+///
+/// assert_eq!(horner_debug_string(&[2.0, 3.0, 4.0]), "4x^2 + 3x + 2");
+/// assert_eq!(horner_debug_string(&[-1.0, 0.0, 0.5, -1.0]), "-x^3 + 0.5x^2 - 1");
+/// assert_eq!(horner_debug_string(&[0.0, 0.0]), "0");
+/// ```
+pub fn horner_debug_string(coeffs: &[f64]) -> String {
+    let mut s = String::new();
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        if c == 0.0 {
+            continue;
+        }
+        let magnitude = c.abs();
+        match (s.is_empty(), c.is_sign_negative()) {
+            (true, false) => {}
+            (true, true) => s.push('-'),
+            (false, false) => s.push_str(" + "),
+            (false, true) => s.push_str(" - "),
+        }
+        if magnitude != 1.0 || k == 0 {
+            // writing to a `String` never fails
+            let _ = write!(s, "{magnitude}");
+        }
+        match k {
+            0 => {}
+            1 => s.push('x'),
+            _ => {
+                let _ = write!(s, "x^{k}");
+            }
+        }
+    }
+    if s.is_empty() {
+        s.push('0');
+    }
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_debug_string() {
+        use super::horner_debug_string;
+        assert_eq!(horner_debug_string(&[]), "0");
+        assert_eq!(horner_debug_string(&[0., 0., 0.]), "0");
+        assert_eq!(horner_debug_string(&[-0.]), "0");
+        assert_eq!(horner_debug_string(&[5.]), "5");
+        assert_eq!(horner_debug_string(&[-5.]), "-5");
+        assert_eq!(horner_debug_string(&[1.]), "1");
+        assert_eq!(horner_debug_string(&[-1.]), "-1");
+        assert_eq!(horner_debug_string(&[0., 1.]), "x");
+        assert_eq!(horner_debug_string(&[0., -1.]), "-x");
+        assert_eq!(horner_debug_string(&[0., 0., 1.]), "x^2");
+        assert_eq!(horner_debug_string(&[2., 3., 4.]), "4x^2 + 3x + 2");
+        assert_eq!(horner_debug_string(&[-2., -3., -4.]), "-4x^2 - 3x - 2");
+        assert_eq!(horner_debug_string(&[1., 0., -1., 0.]), "-x^2 + 1");
+        assert_eq!(horner_debug_string(&[0., -2.5, 0., 1.]), "x^3 - 2.5x");
+        assert_eq!(
+            horner_debug_string(&[1., 1., 0., 0., 0., 0., 0., 0., 0., 0., 1.]),
+            "x^10 + x + 1"
+        );
+        assert_eq!(
+            horner_debug_string(&[f64::NAN, f64::NEG_INFINITY]),
+            "-infx + NaN"
+        );
+    }
+}
@@ -0,0 +1,189 @@
+//! Division of polynomials.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+#[cfg(feature = "alloc")]
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// recording all the intermediate values.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Writes the successive values of the accumulator into `out`, from the leading coefficient
+/// `out[0] = cₙ` through `out[k] = out[k - 1] x + cₙ₋ₖ` down to the value `out[n] = p(x)`.
+/// This is the row computed by [synthetic division](https://en.wikipedia.org/wiki/Synthetic_division)
+/// by `(X - x)`: `out[..n]` are the coefficients of the quotient, listed from highest order
+/// to zeroth, and `out[n]` is the remainder. No allocation is required.
+#[cfg_attr(
+    feature = "alloc",
+    doc = "The same quotient, in the opposite order, and remainder are returned by [`deflate`]."
+)]
+///
+/// # Panics
+///
+/// Panics if `coeffs` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_table;
+///
+/// // x³ - 7x + 6 = (x - 2)(x² + 2x - 3) + 0
+/// let mut out = [0; 4];
+/// horner_table(2, &[6, -7, 0, 1], &mut out);
+///
+/// assert_eq!(out, [1, 2, -3, 0]);
+/// ```
+#[track_caller]
+pub fn horner_table<T>(x: T, coeffs: &[T], out: &mut [T])
+where
+    T: Clone,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    assert_eq!(
+        coeffs.len(),
+        out.len(),
+        "the coefficients and the output buffer must have the same length"
+    );
+    let mut prev: Option<&T> = None;
+    for (c, y) in coeffs.iter().rev().zip(out.iter_mut()) {
+        *y = match prev {
+            None => c.clone(),
+            Some(acc) => acc.clone() * &x + c,
+        };
+        prev = Some(y);
+    }
+}
+
+/// Divide a polynomial by `(x - r)` with [synthetic division](https://en.wikipedia.org/wiki/Synthetic_division).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the coefficients of the quotient, one fewer than those of the polynomial and also listed
+/// from zeroth order to highest, and the remainder, which is `p(r)`: the intermediate values of
+/// [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method) at `r` are exactly the
+/// coefficients of the quotient, so the remainder is the same as [`horner`](fn@crate::horner)`(r, coeffs)`.
+/// If `r` is a root of the polynomial, the quotient is the deflated polynomial with the remaining roots.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::deflate;
+///
+/// // x² - 3x + 2 = (x - 1)(x - 2)
+/// assert_eq!(deflate(&[2, -3, 1], 1), (vec![-2, 1], 0));
+/// // x² + 1 = (x - 2)(x + 2) + 5
+/// assert_eq!(deflate(&[1, 0, 1], 2), (vec![2, 1], 5));
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn deflate<T>(coeffs: &[T], r: T) -> (Vec<T>, T)
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let mut quotient = Vec::with_capacity(coeffs.len());
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc * &r + c;
+        quotient.push(acc.clone());
+    }
+    quotient.pop();
+    quotient.reverse();
+    (quotient, acc)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_table() {
+        use super::horner_table;
+        use crate::horner;
+        horner_table(3, &[], &mut []);
+        let mut out = [0];
+        horner_table(3, &[5], &mut out);
+        assert_eq!(out, [5]);
+        // (x - 1)(x - 2)(x + 3) = x³ - 7x + 6, with the rows of synthetic division
+        let coeffs = [6, -7, 0, 1];
+        let mut out = [0; 4];
+        horner_table(1, &coeffs, &mut out);
+        assert_eq!(out, [1, 1, -6, 0]);
+        horner_table(-3, &coeffs, &mut out);
+        assert_eq!(out, [1, -3, 2, 0]);
+        let coeffs = [1, -2, 3, -4, 5, -6, 7];
+        let mut out = [0; 7];
+        for x in -8..8 {
+            horner_table(x, &coeffs, &mut out);
+            assert_eq!(out[6], horner(x, &coeffs));
+            // every entry is the value of the polynomial of the leading coefficients
+            for k in 0..7 {
+                assert_eq!(out[k], horner(x, &coeffs[6 - k..]));
+            }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_horner_table_deflate() {
+        use super::{deflate, horner_table};
+        let coeffs = [0.5, -1., 0.25, 2., -3.];
+        let mut out = [0.; 5];
+        for r in -8..8 {
+            let r = r as f64 / 4.;
+            horner_table(r, &coeffs, &mut out);
+            let (mut quotient, remainder) = deflate(&coeffs, r);
+            quotient.reverse();
+            assert_eq!(out[..4], quotient[..]);
+            assert_eq!(out[4], remainder);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the coefficients and the output buffer must have the same length")]
+    fn test_horner_table_wrong_length() {
+        use super::horner_table;
+        horner_table(2, &[1, 2, 3], &mut [0; 2]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_deflate() {
+        use super::deflate;
+        use crate::horner;
+        assert_eq!(deflate(&[], 3), (vec![], 0));
+        assert_eq!(deflate(&[5], 3), (vec![], 5));
+        // (x - 1)(x - 2)(x + 3) = x³ - 7x + 6
+        let coeffs = [6, -7, 0, 1];
+        assert_eq!(deflate(&coeffs, 1), (vec![-6, 1, 1], 0));
+        assert_eq!(deflate(&coeffs, -3), (vec![2, -3, 1], 0));
+        let coeffs = [1, -2, 3, -4, 5, -6, 7];
+        for r in -8..8 {
+            let (quotient, remainder) = deflate(&coeffs, r);
+            assert_eq!(quotient.len(), coeffs.len() - 1);
+            assert_eq!(remainder, horner(r, &coeffs));
+            // (x - r) q(x) + p(r)
+            let mut product = vec![0; coeffs.len()];
+            for (k, q) in quotient.iter().enumerate() {
+                product[k + 1] += q;
+                product[k] -= r * q;
+            }
+            product[0] += remainder;
+            assert_eq!(product, coeffs);
+        }
+        for r in -8..8 {
+            let r = r as f64 / 4.;
+            let coeffs = [0.5, -1., 0.25, 2.];
+            let (quotient, remainder) = deflate(&coeffs, r);
+            assert_eq!(remainder, horner(r, &coeffs));
+            for x in -8..8 {
+                let x = x as f64 / 4.;
+                let y = (x - r) * horner(x, &quotient) + remainder;
+                assert!((y - horner(x, &coeffs)).abs() < 1e-12);
+            }
+        }
+    }
+}
@@ -0,0 +1,279 @@
+//! Error-free transformations and directed rounding, emulated in software.
+//!
+//! - <https://doi.org/10.1137/030601818> (Ogita, Rump, Oishi: Accurate sum and dot product)
+
+/// Splitting constant `2^27 + 1` for Dekker's algorithm.
+const SPLITTER: f64 = 134217729.0;
+
+/// Splitting constant `2^12 + 1` for Dekker's algorithm in single precision.
+const SPLITTER_F32: f32 = 4097.0;
+
+/// Below this magnitude the error of a product may not be representable.
+#[cfg(feature = "softfloat")]
+const TINY: f64 = f64::MIN_POSITIVE * 9007199254740992.0; // 2^-1022 * 2^53
+
+/// Computes `(s, e)` such that `s = fl(a + b)` and `s + e = a + b` exactly.
+///
+/// The error `e` is NaN if the sum overflows.
+#[inline]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    let e = (a - (s - bb)) + (b - bb);
+    (s, e)
+}
+
+/// Splits `a` into two non-overlapping halves of 26 bits each.
+#[inline]
+fn split(a: f64) -> (f64, f64) {
+    let c = SPLITTER * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// Computes `(p, e)` such that `p = fl(a * b)` and `p + e = a * b` exactly,
+/// with Dekker's algorithm (no fused multiply-add is required).
+///
+/// The error `e` is exact only if the product neither overflows nor underflows.
+#[inline]
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    let e = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+    (p, e)
+}
+
+/// Same as [`two_sum`], in single precision.
+#[inline]
+pub(crate) fn two_sum_f32(a: f32, b: f32) -> (f32, f32) {
+    let s = a + b;
+    let bb = s - a;
+    let e = (a - (s - bb)) + (b - bb);
+    (s, e)
+}
+
+/// Splits `a` into two non-overlapping halves of 12 bits each.
+#[inline]
+fn split_f32(a: f32) -> (f32, f32) {
+    let c = SPLITTER_F32 * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+/// Same as [`two_prod`], in single precision.
+#[inline]
+pub(crate) fn two_prod_f32(a: f32, b: f32) -> (f32, f32) {
+    let p = a * b;
+    let (ah, al) = split_f32(a);
+    let (bh, bl) = split_f32(b);
+    let e = ((ah * bh - p) + ah * bl + al * bh) + al * bl;
+    (p, e)
+}
+
+/// Same as [`two_prod`], but the error `e` is NaN if it may be inexact.
+#[cfg(feature = "softfloat")]
+#[inline]
+fn two_prod_checked(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    if (p != 0.0 && p.abs() < TINY) || (p == 0.0 && a != 0.0 && b != 0.0) {
+        return (p, f64::NAN);
+    }
+    let (p, e) = two_prod(a, b);
+    (p, if e.is_finite() { e } else { f64::NAN })
+}
+
+/// Rounds the exact value `s + e` toward zero.
+///
+/// If the rounded value `s` overflowed from finite operands, it is clamped to the finite range.
+#[inline]
+fn toward_zero((s, e): (f64, f64), finite_operands: bool) -> f64 {
+    if s.is_infinite() && finite_operands {
+        f64::MAX.copysign(s)
+    } else if s > 0.0 && e < 0.0 {
+        s.next_down()
+    } else if s < 0.0 && e > 0.0 {
+        s.next_up()
+    } else {
+        s
+    }
+}
+
+/// Computes `a + b` rounded toward zero.
+#[inline]
+pub(crate) fn add_toward_zero(a: f64, b: f64) -> f64 {
+    toward_zero(two_sum(a, b), a.is_finite() && b.is_finite())
+}
+
+/// Computes `a * b` rounded toward zero.
+///
+/// In the subnormal range the result may be rounded to nearest instead.
+#[inline]
+pub(crate) fn mul_toward_zero(a: f64, b: f64) -> f64 {
+    toward_zero(two_prod(a, b), a.is_finite() && b.is_finite())
+}
+
+/// Rounds the exact value `s + e` toward negative infinity.
+///
+/// If the error `e` is unknown (NaN) the result is rounded conservatively.
+#[cfg(feature = "softfloat")]
+#[inline]
+fn down((s, e): (f64, f64)) -> f64 {
+    if e >= 0.0 {
+        s
+    } else {
+        s.next_down()
+    }
+}
+
+/// Rounds the exact value `s + e` toward positive infinity.
+///
+/// If the error `e` is unknown (NaN) the result is rounded conservatively.
+#[cfg(feature = "softfloat")]
+#[inline]
+fn up((s, e): (f64, f64)) -> f64 {
+    if e <= 0.0 {
+        s
+    } else {
+        s.next_up()
+    }
+}
+
+/// Computes `a + b` rounded toward negative infinity.
+#[cfg(feature = "softfloat")]
+#[inline]
+pub(crate) fn add_down(a: f64, b: f64) -> f64 {
+    down(two_sum(a, b))
+}
+
+/// Computes `a + b` rounded toward positive infinity.
+#[cfg(feature = "softfloat")]
+#[inline]
+pub(crate) fn add_up(a: f64, b: f64) -> f64 {
+    up(two_sum(a, b))
+}
+
+/// Computes `a * b` rounded toward negative infinity.
+#[cfg(feature = "softfloat")]
+#[inline]
+pub(crate) fn mul_down(a: f64, b: f64) -> f64 {
+    down(two_prod_checked(a, b))
+}
+
+/// Computes `a * b` rounded toward positive infinity.
+#[cfg(feature = "softfloat")]
+#[inline]
+pub(crate) fn mul_up(a: f64, b: f64) -> f64 {
+    up(two_prod_checked(a, b))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_two_sum() {
+        assert_eq!(two_sum(1., 2.), (3., 0.));
+        assert_eq!(two_sum(1., 1e-20), (1., 1e-20));
+        assert_eq!(two_sum(1e-20, 1.), (1., 1e-20));
+        let (s, e) = two_sum(0.1, 0.2);
+        assert_eq!(s, 0.1 + 0.2);
+        assert!(e != 0.);
+        assert!(two_sum(f64::MAX, f64::MAX).1.is_nan());
+    }
+
+    #[test]
+    fn test_two_prod() {
+        assert_eq!(two_prod(3., 7.), (21., 0.));
+        let a = 1. + f64::EPSILON;
+        assert_eq!(
+            two_prod(a, a),
+            (1. + 2. * f64::EPSILON, f64::EPSILON * f64::EPSILON)
+        );
+        assert_eq!(two_prod(0., 5.), (0., 0.));
+    }
+
+    #[test]
+    fn test_eft_f32() {
+        assert_eq!(two_sum_f32(1., 2.), (3., 0.));
+        assert_eq!(two_sum_f32(1., 1e-10), (1., 1e-10));
+        assert_eq!(two_prod_f32(3., 7.), (21., 0.));
+        let a = 1. + f32::EPSILON;
+        assert_eq!(
+            two_prod_f32(a, a),
+            (1. + 2. * f32::EPSILON, f32::EPSILON * f32::EPSILON)
+        );
+        // the transformations are exact, as checked in double precision
+        let mut state = 0x9e37_79b9_u32;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            state as f32 / u32::MAX as f32 - 0.5
+        };
+        for _ in 0..1000 {
+            let (a, b) = (next(), next() * 1e3);
+            let (s, e) = two_sum_f32(a, b);
+            assert_eq!(s as f64 + e as f64, a as f64 + b as f64);
+            let (p, e) = two_prod_f32(a, b);
+            assert_eq!(p as f64 + e as f64, a as f64 * b as f64);
+        }
+    }
+
+    #[cfg(feature = "softfloat")]
+    #[test]
+    fn test_two_prod_checked() {
+        assert_eq!(two_prod_checked(3., 7.), (21., 0.));
+        assert!(two_prod_checked(f64::MAX, 2.).1.is_nan());
+        assert!(two_prod_checked(f64::MIN_POSITIVE, 0.5).1.is_nan());
+        assert_eq!(two_prod_checked(0., 5.), (0., 0.));
+    }
+
+    #[test]
+    fn test_rounding_toward_zero() {
+        assert_eq!(add_toward_zero(1., 2.), 3.);
+        assert_eq!(add_toward_zero(1., 1e-20), 1.);
+        assert_eq!(add_toward_zero(1., -1e-20), 1f64.next_down());
+        assert_eq!(add_toward_zero(-1., 1e-20), (-1f64).next_up());
+        assert_eq!(add_toward_zero(-1., -1e-20), -1.);
+        assert_eq!(add_toward_zero(f64::MAX, f64::MAX), f64::MAX);
+        assert_eq!(add_toward_zero(-f64::MAX, -f64::MAX), -f64::MAX);
+        assert_eq!(add_toward_zero(f64::INFINITY, 1.), f64::INFINITY);
+
+        assert_eq!(mul_toward_zero(3., 7.), 21.);
+        // 0.1 * 0.1 is rounded up to nearest
+        assert_eq!(mul_toward_zero(0.1, 0.1), (0.1f64 * 0.1).next_down());
+        assert_eq!(mul_toward_zero(-0.1, 0.1), (-0.1f64 * 0.1).next_up());
+        let a = 1. + f64::EPSILON;
+        assert_eq!(mul_toward_zero(a, a), 1. + 2. * f64::EPSILON);
+        assert_eq!(mul_toward_zero(-a, a), -1. - 2. * f64::EPSILON);
+        let b = 1. - f64::EPSILON / 2.;
+        assert_eq!(mul_toward_zero(a, b), 1.);
+        assert_eq!(mul_toward_zero(-a, b), -1.);
+        assert_eq!(mul_toward_zero(f64::MAX, 2.), f64::MAX);
+        assert_eq!(mul_toward_zero(f64::INFINITY, 2.), f64::INFINITY);
+    }
+
+    #[cfg(feature = "softfloat")]
+    #[test]
+    fn test_directed_rounding() {
+        assert_eq!(add_down(1., 2.), 3.);
+        assert_eq!(add_up(1., 2.), 3.);
+        assert_eq!(add_down(1., 1e-20), 1.);
+        assert_eq!(add_up(1., 1e-20), 1f64.next_up());
+        assert_eq!(add_down(1., -1e-20), 1f64.next_down());
+        assert_eq!(add_up(1., -1e-20), 1.);
+        assert!(add_down(0.1, 0.2) < add_up(0.1, 0.2));
+        assert_eq!(add_down(f64::MAX, f64::MAX), f64::MAX);
+        assert_eq!(add_up(f64::MAX, f64::MAX), f64::INFINITY);
+
+        assert_eq!(mul_down(3., 7.), 21.);
+        assert_eq!(mul_up(3., 7.), 21.);
+        assert!(mul_down(0.1, 0.1) < mul_up(0.1, 0.1));
+        assert_eq!(mul_up(0.1, 0.1).next_down(), mul_down(0.1, 0.1));
+        assert_eq!(mul_down(f64::MAX, 2.), f64::MAX);
+        assert_eq!(mul_up(f64::MAX, 2.), f64::INFINITY);
+        assert!(mul_down(f64::MIN_POSITIVE, 1e-300) < 0.);
+        assert!(mul_up(f64::MIN_POSITIVE, 1e-300) > 0.);
+    }
+}
@@ -0,0 +1,521 @@
+//! Evaluation with Estrin's scheme on coefficients known at runtime.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::mem;
+use core::ops::{Add, Mul};
+#[cfg(feature = "alloc")]
+use num_traits::MulAdd;
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+/// using a caller-provided scratch buffer instead of recursion.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The coefficients are combined pairwise as `a₀ + x*a₁` into `scratch`, then `x` is squared
+/// and the process is repeated in place until a single value remains.
+/// The result is the same as the one computed by [`estrin!`](macro@crate::estrin).
+///
+/// The only memory used is the first `⌈n/2⌉` elements of `scratch`, where `n = coeffs.len()`,
+/// plus a constant amount of stack, regardless of the order of the polynomial.
+/// This makes it suitable for targets with a constrained stack, such as embedded and WASM.
+///
+/// # Panics
+///
+/// Panics if `scratch` is shorter than `⌈n/2⌉`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::estrin_iterative;
+///
+/// let x = 7;
+/// let mut scratch = [0; 2];
+///
+/// assert_eq!(estrin_iterative(x, &[], &mut scratch), 0);
+/// assert_eq!(estrin_iterative(x, &[0], &mut scratch), 0);
+///
+/// assert_eq!(
+///     estrin_iterative(x, &[2, 3, 4], &mut scratch),
+///     2 + x * (3 + x * 4)
+/// );
+/// ```
+#[track_caller]
+pub fn estrin_iterative<T>(mut x: T, coeffs: &[T], scratch: &mut [T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let n = coeffs.len();
+    match n {
+        0 => return T::zero(),
+        1 => return coeffs[0].clone(),
+        _ => {}
+    }
+    let mut len = n.div_ceil(2);
+    assert!(
+        scratch.len() >= len,
+        "scratch buffer too short: the length is {} but it must be at least {len}",
+        scratch.len()
+    );
+
+    for (s, pair) in scratch.iter_mut().zip(coeffs.chunks(2)) {
+        *s = match pair {
+            [a0, a1] => a1.clone() * &x + a0,
+            [a0] => a0.clone(),
+            _ => unreachable!(),
+        };
+    }
+
+    while len > 1 {
+        x = x.clone() * &x;
+        for i in 0..len / 2 {
+            let a0 = mem::replace(&mut scratch[2 * i], T::zero());
+            let a1 = mem::replace(&mut scratch[2 * i + 1], T::zero());
+            scratch[i] = a1 * &x + &a0;
+        }
+        if len % 2 == 1 {
+            scratch[len / 2] = mem::replace(&mut scratch[len - 1], T::zero());
+        }
+        len = len.div_ceil(2);
+    }
+
+    mem::replace(&mut scratch[0], T::zero())
+}
+
+/// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+/// specialized for [arrays](primitive@array).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`estrin_iterative`], with a scratch buffer on the stack sized by `N`. Since the order
+/// of the polynomial is known at compile time, so are the loop bounds, which allows the optimizer
+/// to unroll the loops for small `N`.
+/// The result is the same as the one computed by [`estrin_iterative`].
+///
+/// Like for [`horner_array`](fn@crate::horner_array), the length of the array
+/// type-checks the order of the polynomial.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::estrin_array;
+///
+/// let x = 7;
+///
+/// assert_eq!(estrin_array(x, &[]), 0);
+/// assert_eq!(
+///     //               v-- this is one plus the order of the polynomial
+///     estrin_array::<_, 4>(x, &[2, 3, 4, 5]),
+///     2 + x * (3 + x * (4 + x * 5))
+/// );
+/// ```
+#[inline]
+#[track_caller]
+pub fn estrin_array<T, const N: usize>(mut x: T, coeffs: &[T; N]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    // the same reduction as `estrin_iterative`, inlined so that it sees the constant length
+    let mut scratch: [T; N] = core::array::from_fn(|i| match coeffs.get(2 * i..2 * i + 2) {
+        Some([a0, a1]) => a1.clone() * &x + a0,
+        _ => coeffs.get(2 * i).cloned().unwrap_or_else(T::zero),
+    });
+    let mut len = N.div_ceil(2);
+    // a constant number of levels, instead of `while len > 1`, to help the unrolling
+    for _ in 0..len.next_power_of_two().trailing_zeros() {
+        x = x.clone() * &x;
+        for i in 0..len / 2 {
+            let a0 = mem::replace(&mut scratch[2 * i], T::zero());
+            let a1 = mem::replace(&mut scratch[2 * i + 1], T::zero());
+            scratch[i] = a1 * &x + &a0;
+        }
+        if len % 2 == 1 {
+            scratch[len / 2] = mem::replace(&mut scratch[len - 1], T::zero());
+        }
+        len = len.div_ceil(2);
+    }
+    match scratch.first_mut() {
+        Some(s) => mem::replace(s, T::zero()),
+        None => T::zero(),
+    }
+}
+
+/// Number of points evaluated together by [`estrin_many`].
+const LANES: usize = 4;
+
+/// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme)
+/// at many points.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Writes the value at `xs[i]` into `out[i]`, like [`horner_many`](fn@crate::horner_many).
+/// The points are processed in groups of four, which share the schedule of the reduction: the
+/// same pair of subtrees is combined with the same power `x^(2ᵏ)` for all the points of a group
+/// at once, giving the compiler independent operations to interleave or vectorize.
+/// The subtrees are combined as soon as they are complete, so only `O(log n)` partial results
+/// are kept on the stack and no scratch buffer is needed. The results are the same as those
+/// of [`estrin_iterative`].
+///
+/// Compared to Horner's method, the dependency chain of every point has length `O(log n)`
+/// instead of `O(n)`, which pays off for high degrees, at the cost of some accuracy.
+///
+/// # Panics
+///
+/// Panics if `xs` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::estrin_many;
+///
+/// let mut out = [0.0; 3];
+/// estrin_many(&[0.0, 1.0, 7.0], &[2.0, 3.0, 4.0], &mut out);
+///
+/// assert_eq!(out, [2.0, 9.0, 219.0]);
+/// ```
+#[track_caller]
+pub fn estrin_many(xs: &[f64], coeffs: &[f64], out: &mut [f64]) {
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "the points and the output buffer must have the same length"
+    );
+    // a slice has fewer than 2⁶⁴ coefficients, so 64 levels are enough
+    let mut powers = [[0.0; LANES]; 64];
+    let mut stack = [([0.0; LANES], 0); 64];
+    for (xs, out) in xs.chunks(LANES).zip(out.chunks_mut(LANES)) {
+        let mut x = [0.0; LANES];
+        x[..xs.len()].copy_from_slice(xs);
+        let y = estrin_lanes(x, coeffs, &mut powers, &mut stack);
+        out.copy_from_slice(&y[..out.len()]);
+    }
+}
+
+type Lanes = [f64; LANES];
+
+/// Evaluates with Estrin's scheme at the points `x`, combining the subtrees like a binary counter.
+///
+/// The buffers hold the powers `x^(2ᵏ)` and the complete subtrees with their levels.
+fn estrin_lanes(
+    x: Lanes,
+    coeffs: &[f64],
+    powers: &mut [Lanes; 64],
+    stack: &mut [(Lanes, usize); 64],
+) -> Lanes {
+    let combine = |a0: &Lanes, a1: &Lanes, p: &Lanes| -> Lanes {
+        core::array::from_fn(|i| a1[i] * p[i] + a0[i])
+    };
+    // powers[k] = x^(2ᵏ)
+    powers[0] = x;
+    let mut computed = 1;
+    let mut power = |k: usize| -> Lanes {
+        while computed <= k {
+            let p = powers[computed - 1];
+            powers[computed] = combine(&[0.0; LANES], &p, &p);
+            computed += 1;
+        }
+        powers[k]
+    };
+    // complete subtrees, with their levels decreasing from the bottom of the stack
+    let mut len = 0;
+    for pair in coeffs.chunks(2) {
+        let mut node = match *pair {
+            [a0, a1] => combine(&[a0; LANES], &[a1; LANES], &x),
+            [a0] => [a0; LANES],
+            _ => unreachable!(),
+        };
+        let mut level = 0;
+        while len > 0 && stack[len - 1].1 == level {
+            len -= 1;
+            level += 1;
+            node = combine(&stack[len].0, &node, &power(level));
+        }
+        stack[len] = (node, level);
+        len += 1;
+    }
+    // the incomplete tail is attached to the complete subtrees on its left
+    let Some(((mut acc, _), rest)) = stack[..len].split_last() else {
+        return [0.0; LANES];
+    };
+    for (left, level) in rest.iter().rev() {
+        acc = combine(left, &acc, &power(level + 1));
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`estrin_iterative`], but the scratch buffer of `⌈n/2⌉` elements is allocated
+/// internally. The result is the same as the one computed by [`estrin!`](macro@crate::estrin),
+/// with the coefficients known only at runtime; for integer types it is also the same as
+/// the one computed by [`horner`](fn@crate::horner).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::estrin;
+///
+/// let x = 7;
+///
+/// assert_eq!(estrin(x, &[]), 0);
+/// assert_eq!(estrin(x, &[0]), 0);
+///
+/// let coeffs = vec![2, 3, 4];
+/// assert_eq!(estrin(x, &coeffs), 2 + x * (3 + x * 4));
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn estrin<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let mut scratch: Vec<T> = (0..coeffs.len().div_ceil(2)).map(|_| T::zero()).collect();
+    estrin_iterative(x, coeffs, &mut scratch)
+}
+
+/// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+/// using "fused multiply-add" instructions.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`estrin`], but every pair `a₀ + x*a₁` is computed with a single rounding by
+/// [`MulAdd::mul_add`], at every level of the reduction (where `x` is squared).
+/// The result is the same as the one computed by [`estrin_fma!`](macro@crate::estrin_fma),
+/// with the coefficients known only at runtime.
+/// For floating point types, the `std` or `libm` feature is required.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::estrin_fma;
+///
+/// let x = 7.0;
+///
+/// assert_eq!(estrin_fma(x, &[]), 0.0);
+/// assert_eq!(estrin_fma(x, &[2.0, 3.0, 4.0]), 2.0 + x * (3.0 + x * 4.0));
+/// ```
+#[cfg(feature = "alloc")]
+#[track_caller]
+pub fn estrin_fma<T>(mut x: T, coeffs: &[T]) -> T
+where
+    T: MulAdd<Output = T> + Mul<Output = T> + Zero + Copy,
+{
+    let mut scratch = vec![T::zero(); coeffs.len().div_ceil(2)];
+    for (s, pair) in scratch.iter_mut().zip(coeffs.chunks(2)) {
+        *s = match *pair {
+            [a0, a1] => x.mul_add(a1, a0),
+            [a0] => a0,
+            _ => unreachable!(),
+        };
+    }
+    let mut len = scratch.len();
+    while len > 1 {
+        x = x * x;
+        for i in 0..len / 2 {
+            scratch[i] = x.mul_add(scratch[2 * i + 1], scratch[2 * i]);
+        }
+        if len % 2 == 1 {
+            scratch[len / 2] = scratch[len - 1];
+        }
+        len = len.div_ceil(2);
+    }
+    scratch.first().copied().unwrap_or_else(T::zero)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_estrin_iterative() {
+        use super::estrin_iterative;
+        use crate::horner;
+        let mut scratch = [0; 16];
+        for x in 0..32 {
+            assert_eq!(estrin_iterative(x, &[], &mut []), 0);
+            assert_eq!(estrin_iterative(x, &[1], &mut []), 1);
+            assert_eq!(estrin_iterative(x, &[1, 2], &mut scratch), 1 + 2 * x);
+            assert_eq!(
+                estrin_iterative(x, &[1, 2, 3, 4, 5], &mut scratch),
+                1 + x * (2 + x * (3 + x * (4 + x * 5)))
+            );
+        }
+        let coeffs: Vec<i64> = (1..=32).collect();
+        for x in -3..=3 {
+            for n in 0..=coeffs.len() {
+                assert_eq!(
+                    estrin_iterative(x, &coeffs[..n], &mut scratch),
+                    horner(x, &coeffs[..n])
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_estrin_iterative_matches_macro() {
+        use super::estrin_iterative;
+        let mut scratch = [0.; 8];
+        for x in 0..32 {
+            let x = x as f32 / 8.;
+            assert_eq!(
+                estrin_iterative(x, &[1., 2., 3.], &mut scratch),
+                crate::estrin!(x; 1., 2., 3.)
+            );
+            assert_eq!(
+                estrin_iterative(x, &[1., 2., 3., 4., 5.], &mut scratch),
+                crate::estrin!(x; 1., 2., 3., 4., 5.)
+            );
+            assert_eq!(
+                estrin_iterative(
+                    x,
+                    &[1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11.],
+                    &mut scratch
+                ),
+                crate::estrin!(x; 1., 2., 3., 4., 5., 6., 7., 8., 9., 10., 11.)
+            );
+        }
+    }
+
+    #[test]
+    fn test_estrin_array() {
+        use super::{estrin_array, estrin_iterative};
+        use crate::horner_array;
+        fn check<const N: usize>() {
+            let ints: [i64; N] = core::array::from_fn(|k| (k as i64 * 7) % 11 - 5);
+            let floats: [f64; N] = core::array::from_fn(|k| 1. / (k + 1) as f64);
+            let mut scratch = [0.; N];
+            for x in -4..=4 {
+                assert_eq!(estrin_array(x, &ints), horner_array(x, &ints));
+                let x = x as f64 / 5.;
+                let value = estrin_array(x, &floats);
+                assert_eq!(value, estrin_iterative(x, &floats, &mut scratch));
+                let reference = horner_array(x, &floats);
+                assert!((value - reference).abs() <= 4. * f64::EPSILON * reference.abs());
+            }
+        }
+        check::<1>();
+        check::<2>();
+        check::<3>();
+        check::<4>();
+        check::<5>();
+        check::<6>();
+        check::<7>();
+        check::<8>();
+        assert_eq!(estrin_array(3, &[]), 0);
+        assert_eq!(
+            estrin_array(0.5, &[1., 2., 3., 4., 5.]),
+            crate::estrin!(0.5; 1., 2., 3., 4., 5.)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_estrin() {
+        use super::{estrin, estrin_iterative};
+        use crate::horner;
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 21) as i64 - 10
+        };
+        for n in 0..=20 {
+            for _ in 0..8 {
+                let coeffs: Vec<i64> = (0..n).map(|_| random()).collect();
+                for x in -3..=3 {
+                    assert_eq!(estrin(x, &coeffs), horner(x, &coeffs));
+                }
+                let coeffs: Vec<f64> = coeffs.iter().map(|&c| c as f64 / 3.).collect();
+                let mut scratch = [0.; 10];
+                for x in -8..=8 {
+                    let x = x as f64 / 7.;
+                    assert_eq!(
+                        estrin(x, &coeffs),
+                        estrin_iterative(x, &coeffs, &mut scratch)
+                    );
+                }
+            }
+        }
+        assert_eq!(estrin(2., &[]), 0.);
+        assert_eq!(
+            estrin(0.5, &[1., 2., 3., 4., 5.]),
+            crate::estrin!(0.5; 1., 2., 3., 4., 5.)
+        );
+    }
+
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    #[test]
+    fn test_estrin_fma() {
+        use super::{estrin, estrin_fma};
+        use crate::horner_fma;
+        let coeffs: Vec<i64> = (1..=32).map(|k| (k * 7) % 11 - 5).collect();
+        let floats: Vec<f64> = (1..=32).map(|k| 1. / k as f64).collect();
+        for n in 1..=32 {
+            for x in -3..=3 {
+                assert_eq!(estrin_fma(x, &coeffs[..n]), estrin(x, &coeffs[..n]));
+            }
+            for x in -16..=16 {
+                let x = x as f64 / 17.;
+                let value = estrin_fma(x, &floats[..n]);
+                let reference = horner_fma(x, &floats[..n]);
+                assert!((value - reference).abs() <= 4. * f64::EPSILON * reference.abs());
+            }
+        }
+        assert_eq!(estrin_fma(2, &[]), 0);
+        for x in 0..32 {
+            let x = x as f32 / 8.;
+            assert_eq!(
+                estrin_fma(x, &[1. / 3., 2. / 7., 3. / 11., 4. / 13., 5. / 17.]),
+                crate::estrin_fma!(x; 1. / 3., 2. / 7., 3. / 11., 4. / 13., 5. / 17.)
+            );
+        }
+    }
+
+    #[test]
+    fn test_estrin_many() {
+        use super::{estrin_iterative, estrin_many};
+        use crate::horner_many;
+        let coeffs: [f64; 70] = core::array::from_fn(|k| ((k * 7) % 11) as f64 / 5. - 1.);
+        let xs: [f64; 23] = core::array::from_fn(|k| k as f64 / 11. - 1.);
+        let (mut out, mut reference) = ([0.; 23], [0.; 23]);
+        let mut scratch = [0.; 35];
+        for n in 0..=coeffs.len() {
+            // every number of points, including incomplete groups
+            for m in [0, 1, 3, 4, 5, 23] {
+                estrin_many(&xs[..m], &coeffs[..n], &mut out[..m]);
+                horner_many(&xs[..m], &coeffs[..n], &mut reference[..m]);
+                for i in 0..m {
+                    let expected = estrin_iterative(xs[i], &coeffs[..n], &mut scratch);
+                    assert_eq!(out[i], expected);
+                    let tol = 1e-13 * coeffs[..n].iter().map(|c| c.abs()).sum::<f64>();
+                    assert!((out[i] - reference[i]).abs() <= tol);
+                }
+            }
+        }
+        estrin_many(&[], &[], &mut []);
+        estrin_many(&[2.], &[], &mut out[..1]);
+        assert_eq!(out[0], 0.);
+    }
+
+    #[test]
+    #[should_panic(expected = "the points and the output buffer must have the same length")]
+    fn test_estrin_many_wrong_length() {
+        use super::estrin_many;
+        estrin_many(&[1., 2.], &[1., 2., 3.], &mut [0.; 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "scratch buffer too short")]
+    fn test_estrin_iterative_short_scratch() {
+        use super::estrin_iterative;
+        estrin_iterative(2, &[1, 2, 3, 4, 5], &mut [0; 2]);
+    }
+}
@@ -0,0 +1,83 @@
+//! Runtime selection of the evaluation algorithm.
+
+use alloc::boxed::Box;
+
+use crate::compensated::horner_compensated;
+use crate::{estrin, horner};
+
+/// Evaluator of polynomials with `f64` coefficients, chosen at runtime.
+type Evaluator = Box<dyn Fn(f64, &[f64]) -> f64>;
+
+/// Returns the evaluation algorithm with the given name, or `None` if the name is unknown.
+///
+/// The returned closure takes the point of evaluation and the coefficients,
+/// listed from zeroth order to highest, like [`horner`](fn@crate::horner).
+/// This allows to select the algorithm from a configuration, storing the evaluator
+/// instead of matching on the name at each call site.
+///
+/// The valid names are:
+/// - `"horner"`: [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+///   same as [`horner`](fn@crate::horner);
+/// - `"estrin"`: [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+///   same as [`estrin`](fn@crate::estrin);
+/// - `"horner_fma"`: Horner's method with "fused multiply-add" instructions, same as
+///   [`horner_fma`](fn@crate::horner_fma), only available with either the `std` or the `libm` feature;
+/// - `"horner_compensated"`: the compensated Horner scheme, as accurate as if computed
+///   with twice the working precision, same as [`horner_compensated`](fn@crate::horner_compensated);
+/// - `"horner_adaptive"`: same as [`horner_adaptive`](fn@crate::horner_adaptive).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::make_evaluator;
+///
+/// let eval = make_evaluator("estrin").unwrap();
+///
+/// assert_eq!(eval(7.0, &[2.0, 3.0, 4.0]), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// assert!(make_evaluator("clenshaw").is_none());
+/// ```
+pub fn make_evaluator(name: &str) -> Option<Evaluator> {
+    let evaluator: Evaluator = match name {
+        "horner" => Box::new(horner::<f64>),
+        "estrin" => Box::new(estrin::<f64>),
+        #[cfg(any(feature = "std", feature = "libm"))]
+        "horner_fma" => Box::new(crate::horner_fma::<f64>),
+        "horner_compensated" => Box::new(horner_compensated),
+        "horner_adaptive" => Box::new(crate::horner_adaptive),
+        _ => return None,
+    };
+    Some(evaluator)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_make_evaluator() {
+        use super::make_evaluator;
+        use crate::horner;
+        let names = [
+            "horner",
+            "estrin",
+            #[cfg(any(feature = "std", feature = "libm"))]
+            "horner_fma",
+            "horner_compensated",
+            "horner_adaptive",
+        ];
+        let coeffs = [1., 2., 3., 4., 5.];
+        for name in names {
+            let eval = make_evaluator(name).unwrap();
+            for x in -32..32 {
+                let x = x as f64;
+                assert_eq!(eval(x, &[]), 0., "{name}");
+                assert_eq!(eval(x, &[2.]), 2., "{name}");
+                for n in 0..=coeffs.len() {
+                    assert_eq!(eval(x, &coeffs[..n]), horner(x, &coeffs[..n]), "{name}");
+                }
+            }
+        }
+        assert!(make_evaluator("").is_none());
+        assert!(make_evaluator("Horner").is_none());
+        #[cfg(not(any(feature = "std", feature = "libm")))]
+        assert!(make_evaluator("horner_fma").is_none());
+    }
+}
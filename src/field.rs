@@ -0,0 +1,296 @@
+//! Evaluation over user-defined fields and semirings, such as the prime fields used in cryptography.
+
+/// Minimal interface to the arithmetic of a field.
+///
+/// This is meant to be implemented, possibly through a newtype, for external field element types
+/// (e.g. prime field elements in Montgomery form) which don't implement the standard
+/// operator traits in the way required by [`horner`](fn@crate::horner).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::Field;
+///
+/// /// The field with 7 elements.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct F7(u8);
+///
+/// impl Field for F7 {
+///     fn zero() -> Self {
+///         F7(0)
+///     }
+///     fn add(&self, other: &Self) -> Self {
+///         F7((self.0 + other.0) % 7)
+///     }
+///     fn mul(&self, other: &Self) -> Self {
+///         F7((self.0 * other.0) % 7)
+///     }
+/// }
+/// ```
+pub trait Field: Sized {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the sum `self + other`.
+    fn add(&self, other: &Self) -> Self;
+
+    /// Returns the product `self * other`.
+    fn mul(&self, other: &Self) -> Self;
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// over a [`Field`].
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_field, Field};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct F7(u8);
+///
+/// impl Field for F7 {
+///     fn zero() -> Self {
+///         F7(0)
+///     }
+///     fn add(&self, other: &Self) -> Self {
+///         F7((self.0 + other.0) % 7)
+///     }
+///     fn mul(&self, other: &Self) -> Self {
+///         F7((self.0 * other.0) % 7)
+///     }
+/// }
+///
+/// // 2 + 3*5 + 4*5² = 117 = 5 (mod 7)
+/// assert_eq!(horner_field(F7(5), &[F7(2), F7(3), F7(4)]), F7(5));
+/// ```
+pub fn horner_field<F: Field>(x: F, coeffs: &[F]) -> F {
+    coeffs
+        .iter()
+        .rfold(F::zero(), |acc: F, c: &F| acc.mul(&x).add(c))
+}
+
+/// Minimal interface to the arithmetic of a semiring.
+///
+/// Horner's method only needs addition and multiplication, so it works over any semiring,
+/// such as the tropical semiring `(max, +)` or semirings of matrices. Every [`Field`] is
+/// a semiring through a blanket implementation.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::Semiring;
+///
+/// /// The tropical semiring, where the sum is the maximum and the product is the sum.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Tropical(f64);
+///
+/// impl Semiring for Tropical {
+///     fn zero() -> Self {
+///         Tropical(f64::NEG_INFINITY)
+///     }
+///     fn add(&self, other: &Self) -> Self {
+///         Tropical(self.0.max(other.0))
+///     }
+///     fn mul(&self, other: &Self) -> Self {
+///         Tropical(self.0 + other.0)
+///     }
+/// }
+/// ```
+pub trait Semiring: Sized {
+    /// Returns the additive identity.
+    fn zero() -> Self;
+
+    /// Returns the sum `self + other`.
+    fn add(&self, other: &Self) -> Self;
+
+    /// Returns the product `self * other`.
+    fn mul(&self, other: &Self) -> Self;
+}
+
+impl<F: Field> Semiring for F {
+    fn zero() -> Self {
+        Field::zero()
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        Field::add(self, other)
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        Field::mul(self, other)
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// over a [`Semiring`], skipping the multiplications of the identity.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The accumulator is initialized with the leading coefficient, so the multiplication of the
+/// zero is never performed. If the multiplicative identity `one` is given, whenever the
+/// accumulator is equal to it the step `acc * x` is replaced by a copy of `x`; this requires
+/// the comparison with [`PartialEq`], which should be cheap compared to the multiplication.
+/// It pays off when the multiplication is expensive, as with matrices or big numbers, and the
+/// polynomial is monic, in which case the first multiplication is skipped.
+/// The result is the same as without the identity.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_semiring, Semiring};
+///
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Tropical(f64);
+///
+/// impl Semiring for Tropical {
+///     fn zero() -> Self {
+///         Tropical(f64::NEG_INFINITY)
+///     }
+///     fn add(&self, other: &Self) -> Self {
+///         Tropical(self.0.max(other.0))
+///     }
+///     fn mul(&self, other: &Self) -> Self {
+///         Tropical(self.0 + other.0)
+///     }
+/// }
+///
+/// // max(2, 3 + x, 4 + 2x) at x = 5
+/// let coeffs = [Tropical(2.0), Tropical(3.0), Tropical(4.0)];
+///
+/// assert_eq!(horner_semiring(Tropical(5.0), &coeffs, None), Tropical(14.0));
+/// // the identity of the tropical product is 0
+/// assert_eq!(horner_semiring(Tropical(5.0), &coeffs, Some(Tropical(0.0))), Tropical(14.0));
+/// ```
+pub fn horner_semiring<S>(x: S, coeffs: &[S], one: Option<S>) -> S
+where
+    S: Semiring + Clone + PartialEq,
+{
+    let Some((last, rest)) = coeffs.split_last() else {
+        return S::zero();
+    };
+    let mut acc = last.clone();
+    for c in rest.iter().rev() {
+        acc = match &one {
+            Some(one) if acc == *one => x.clone(),
+            _ => acc.mul(&x),
+        }
+        .add(c);
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Field;
+
+    /// Prime field of order `P < 2^32`.
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Fp<const P: u64>(u64);
+
+    impl<const P: u64> Field for Fp<P> {
+        fn zero() -> Self {
+            Fp(0)
+        }
+        fn add(&self, other: &Self) -> Self {
+            Fp((self.0 + other.0) % P)
+        }
+        fn mul(&self, other: &Self) -> Self {
+            Fp((self.0 * other.0) % P)
+        }
+    }
+
+    #[test]
+    fn test_horner_field() {
+        use super::horner_field;
+        use crate::horner;
+        const P: u64 = 65521;
+        let coeffs = [1, 2, 3, 4, 5, 6];
+        let field_coeffs = coeffs.map(Fp::<P>);
+        for x in 0..32 {
+            assert_eq!(horner_field(Fp::<P>(x), &[]), Fp(0));
+            assert_eq!(horner_field(Fp::<P>(x), &[Fp(1)]), Fp(1));
+            assert_eq!(
+                horner_field(Fp::<P>(x), &field_coeffs),
+                Fp(horner(x, &coeffs) % P)
+            );
+        }
+        // the polynomial x^(P-1) - 1 vanishes on the nonzero elements (Fermat's little theorem)
+        const Q: u64 = 101;
+        let mut coeffs = [Fp::<Q>(0); Q as usize];
+        coeffs[0] = Fp(Q - 1);
+        coeffs[Q as usize - 1] = Fp(1);
+        assert_eq!(horner_field(Fp(0), &coeffs), Fp(Q - 1));
+        for x in 1..Q {
+            assert_eq!(horner_field(Fp(x), &coeffs), Fp(0));
+        }
+    }
+
+    #[test]
+    fn test_horner_semiring() {
+        use super::{horner_field, horner_semiring, Semiring};
+        use core::cell::Cell;
+
+        // fields are semirings
+        const P: u64 = 65521;
+        let coeffs = [1, 2, 3, 4, 5, 6].map(Fp::<P>);
+        for x in 0..32 {
+            let x = Fp::<P>(x);
+            assert_eq!(horner_semiring(x, &[], None), Fp(0));
+            assert_eq!(horner_semiring(x, &coeffs, None), horner_field(x, &coeffs));
+            assert_eq!(
+                horner_semiring(x, &coeffs, Some(Fp(1))),
+                horner_field(x, &coeffs)
+            );
+        }
+
+        // integers counting their multiplications
+        #[derive(Clone, Debug, PartialEq)]
+        struct Counted<'a>(i64, &'a Cell<usize>);
+
+        impl Semiring for Counted<'_> {
+            fn zero() -> Self {
+                unreachable!()
+            }
+            fn add(&self, other: &Self) -> Self {
+                Counted(self.0 + other.0, self.1)
+            }
+            fn mul(&self, other: &Self) -> Self {
+                self.1.set(self.1.get() + 1);
+                Counted(self.0 * other.0, self.1)
+            }
+        }
+
+        let count = Cell::new(0);
+        let one = Counted(1, &count);
+        let n = |k| Counted(k, &count);
+        for x in -8..8 {
+            for (coeffs, skipped) in [
+                (vec![n(1)], 0),
+                (vec![n(3), n(1)], 1),
+                (vec![n(2), n(-1), n(0), n(1)], 1),
+                (vec![n(2), n(-1), n(0), n(5)], 0),
+            ] {
+                let expected = crate::horner(x, &coeffs.iter().map(|c| c.0).collect::<Vec<_>>());
+                count.set(0);
+                assert_eq!(horner_semiring(n(x), &coeffs, None).0, expected);
+                assert_eq!(count.get(), coeffs.len() - 1);
+                count.set(0);
+                assert_eq!(
+                    horner_semiring(n(x), &coeffs, Some(one.clone())).0,
+                    expected
+                );
+                // the first step of a monic polynomial is skipped, and later ones when acc = 1
+                let skips = (coeffs.len() - 1) - count.get();
+                assert!(skips >= skipped);
+            }
+        }
+        // p(x) = x³ at x = 1 has acc = 1 at every step
+        count.set(0);
+        let coeffs = [n(0), n(0), n(0), n(1)];
+        assert_eq!(horner_semiring(n(1), &coeffs, Some(one.clone())).0, 1);
+        assert_eq!(count.get(), 0);
+    }
+}
@@ -0,0 +1,125 @@
+//! Evaluation with fixed-point numbers.
+
+use fixed::traits::Fixed;
+
+/// Arithmetic used by [`horner_fixed`] on overflow.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Overflow {
+    /// Wrap around at the boundary of the type, discarding the high bits.
+    Wrapping,
+    /// Clamp to the range of the type.
+    Saturating,
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// using fixed-point numbers of the [`fixed`](https://docs.rs/fixed) crate.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every step computes the product `acc * x` and then adds the coefficient, with the operations
+/// selected by `overflow`. The result never panics, regardless of whether debug assertions are
+/// enabled, and is computed as follows.
+///
+/// - The exact product is rounded down (toward −∞) to a multiple of `F::DELTA`, as by the
+///   multiplication of the `fixed` crate, while the sum is exact. Without overflow, the error
+///   of the result is thus `∑ eₖ xᵏ` with `-DELTA < eₖ <= 0` for `k < n - 1`, where
+///   `n = coeffs.len()`; in particular it is less than `DELTA ∑ |x|ᵏ` in absolute value.
+/// - With [`Overflow::Wrapping`], every operation which overflows keeps only the low bits of
+///   the result, in two's complement for signed types. If `x` is an integer, the result is
+///   then congruent to the value without overflow modulo `2ⁱ`, where `i = F::INT_NBITS` is the
+///   number of integer bits; otherwise the product shifts the discarded bits back into range
+///   and the result is meaningless.
+/// - With [`Overflow::Saturating`], every operation which overflows is clamped to
+///   `F::MIN` or `F::MAX`, like in [`saturating_horner`](fn@crate::saturating_horner): the
+///   clamping happens at each step, and the subsequent steps keep operating on the clamped
+///   accumulator, so the result is in general not the saturated value without overflow.
+///
+/// # Examples
+///
+/// ```
+/// use fixed::types::I16F16;
+/// use polyeval::{horner_fixed, Overflow};
+///
+/// let x = I16F16::from_num(1.5);
+/// let coeffs = [2, 3, 4].map(I16F16::from_num);
+///
+/// // 2 + 1.5 * (3 + 1.5 * 4) = 15.5, the products are exact
+/// let y = horner_fixed(x, &coeffs, Overflow::Wrapping);
+/// assert_eq!(y, I16F16::from_num(15.5));
+///
+/// // 1 + 256² = 65537 overflows the 16 integer bits
+/// let x = I16F16::from_num(256);
+/// let coeffs = [1, 0, 1].map(I16F16::from_num);
+/// assert_eq!(horner_fixed(x, &coeffs, Overflow::Wrapping), I16F16::from_num(1));
+/// assert_eq!(horner_fixed(x, &coeffs, Overflow::Saturating), I16F16::MAX);
+/// ```
+pub fn horner_fixed<F: Fixed>(x: F, coeffs: &[F], overflow: Overflow) -> F {
+    match overflow {
+        Overflow::Wrapping => coeffs
+            .iter()
+            .rfold(F::ZERO, |acc, &c| acc.wrapping_mul(x).wrapping_add(c)),
+        Overflow::Saturating => coeffs
+            .iter()
+            .rfold(F::ZERO, |acc, &c| acc.saturating_mul(x).saturating_add(c)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_fixed() {
+        use super::{horner_fixed, Overflow};
+        use crate::horner;
+        use fixed::types::{I16F16, U8F8};
+        let coeffs = [0.75, -2.5, 1.125, 0.3, -0.0625];
+        let fixed = coeffs.map(I16F16::from_num);
+        // the coefficients are rounded to multiples of 2⁻¹⁶, use the rounded values as exact
+        let exact = fixed.map(|c| c.to_num::<f64>());
+        for x in -64..=64 {
+            let x = I16F16::from_num(x) / 32;
+            let y = horner_fixed(x, &fixed, Overflow::Wrapping);
+            assert_eq!(horner_fixed(x, &fixed, Overflow::Saturating), y);
+            // the f64 computation is exact, and the products are rounded down
+            let value = horner(x.to_num::<f64>(), &exact);
+            let bound: f64 = (0..coeffs.len() - 1)
+                .map(|k| x.to_num::<f64>().abs().powi(k as i32))
+                .sum();
+            let error = (y.to_num::<f64>() - value) * 65536.;
+            assert!(error.abs() < bound, "{x}: {error} {bound}");
+        }
+        // the products are exact for dyadic points with few bits
+        let x = I16F16::from_num(0.5);
+        let y = horner_fixed(x, &fixed[..3], Overflow::Saturating);
+        assert_eq!(y.to_num::<f64>(), horner(0.5, &exact[..3]));
+        // rounding toward -∞: -DELTA * 0.5 = -DELTA
+        let y = horner_fixed(x, &[I16F16::ZERO, -I16F16::DELTA], Overflow::Wrapping);
+        assert_eq!(y, -I16F16::DELTA);
+        assert_eq!(horner_fixed(x, &[], Overflow::Wrapping), I16F16::ZERO);
+        // overflow with an integer point wraps modulo 2¹⁶
+        let x = I16F16::from_num(300);
+        let coeffs = [7, -2, 1].map(I16F16::from_num);
+        let exact = 7 - 2 * 300 + 300 * 300;
+        assert_eq!(
+            horner_fixed(x, &coeffs, Overflow::Wrapping),
+            I16F16::from_num(exact as i16)
+        );
+        assert_eq!(horner_fixed(x, &coeffs, Overflow::Saturating), I16F16::MAX);
+        assert_eq!(horner_fixed(-x, &coeffs, Overflow::Saturating), I16F16::MAX);
+        let coeffs = [0, 0, 0, 1].map(I16F16::from_num);
+        assert_eq!(horner_fixed(-x, &coeffs, Overflow::Saturating), I16F16::MIN);
+        // the clamping happens at each step: MAX - 100 instead of MAX
+        let coeffs = [-100, 0, 1].map(I16F16::from_num);
+        assert_eq!(
+            horner_fixed(-x, &coeffs, Overflow::Saturating),
+            I16F16::MAX - I16F16::from_num(100)
+        );
+        // unsigned types
+        let x = U8F8::from_num(20);
+        let coeffs = [3, 0, 1].map(U8F8::from_num);
+        assert_eq!(
+            horner_fixed(x, &coeffs, Overflow::Wrapping),
+            U8F8::from_num((3 + 400) % 256)
+        );
+        assert_eq!(horner_fixed(x, &coeffs, Overflow::Saturating), U8F8::MAX);
+    }
+}
@@ -0,0 +1,98 @@
+//! Component-wise evaluation on [`glam`] vectors.
+
+use glam::{Vec3, Vec3A, Vec4};
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// on each component of a [`Vec3`].
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The components are evaluated independently, e.g. to apply a color curve to an RGB color.
+/// The computation is carried out with [`Vec3A`], which is backed by SIMD registers on the
+/// platforms supported by `glam`, and each component of the result is the same as the one
+/// computed by [`horner`](fn@crate::horner).
+///
+/// # Examples
+///
+/// ```
+/// use glam::Vec3;
+/// use polyeval::horner_vec3;
+///
+/// let x = Vec3::new(0.0, 0.5, 1.0);
+///
+/// assert_eq!(horner_vec3(x, &[0.0, 0.5, 0.5]), Vec3::new(0.0, 0.375, 1.0));
+/// ```
+pub fn horner_vec3(x: Vec3, coeffs: &[f32]) -> Vec3 {
+    let x = Vec3A::from(x);
+    coeffs
+        .iter()
+        .rfold(Vec3A::ZERO, |acc, &c| acc * x + Vec3A::splat(c))
+        .into()
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// on each component of a [`Vec4`].
+///
+/// Same as [`horner_vec3`], but for four components, e.g. an RGBA color.
+///
+/// # Examples
+///
+/// ```
+/// use glam::Vec4;
+/// use polyeval::horner_vec4;
+///
+/// let x = Vec4::new(0.0, 0.5, 1.0, 2.0);
+///
+/// assert_eq!(horner_vec4(x, &[0.0, 0.5, 0.5]), Vec4::new(0.0, 0.375, 1.0, 3.0));
+/// ```
+pub fn horner_vec4(x: Vec4, coeffs: &[f32]) -> Vec4 {
+    coeffs
+        .iter()
+        .rfold(Vec4::ZERO, |acc, &c| acc * x + Vec4::splat(c))
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::{Vec3, Vec4};
+
+    #[test]
+    fn test_horner_vec3() {
+        use super::horner_vec3;
+        use crate::horner;
+        let coeffs = [0.1, -0.2, 0.3, -0.4, 0.5];
+        for k in -32..32 {
+            let t = k as f32 / 8.;
+            let x = Vec3::new(t, -t, 1. / (1. + t * t));
+            let y = horner_vec3(x, &coeffs);
+            for i in 0..3 {
+                assert_eq!(y[i], horner(x[i], &coeffs));
+            }
+        }
+        assert_eq!(horner_vec3(Vec3::ONE, &[]), Vec3::ZERO);
+    }
+
+    #[test]
+    fn test_horner_vec4() {
+        use super::horner_vec4;
+        use crate::horner;
+        let coeffs = [0.1, -0.2, 0.3, -0.4, 0.5];
+        for k in -32..32 {
+            let t = k as f32 / 8.;
+            let x = Vec4::new(t, -t, 1. / (1. + t * t), t * t);
+            let y = horner_vec4(x, &coeffs);
+            for i in 0..4 {
+                assert_eq!(y[i], horner(x[i], &coeffs));
+            }
+        }
+        assert_eq!(horner_vec4(Vec4::ONE, &[]), Vec4::ZERO);
+    }
+
+    #[test]
+    #[cfg(any(target_arch = "x86_64", target_arch = "aarch64"))]
+    fn test_simd_backed() {
+        // the SIMD-backed types are 16-byte aligned, unlike the scalar `Vec3`
+        assert_eq!(core::mem::align_of::<glam::Vec3A>(), 16);
+        assert_eq!(core::mem::align_of::<Vec4>(), 16);
+        assert_eq!(core::mem::align_of::<Vec3>(), 4);
+    }
+}
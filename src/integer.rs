@@ -0,0 +1,473 @@
+//! Evaluation with integer arithmetic.
+
+use num_traits::{
+    CheckedAdd, CheckedMul, SaturatingAdd, SaturatingMul, WrappingAdd, WrappingMul, Zero,
+};
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// reporting at which degree the integer arithmetic overflows.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Horner's method processes the coefficients from the highest order down, computing
+/// `acc = acc * x + coeffs[k]` for `k = n, ..., 1, 0`. Returns `Ok(value)` if no operation overflows,
+/// otherwise `Err(k)`, where `k` is the index of the coefficient being accumulated when the checked
+/// multiplication or addition overflowed for the first time.
+///
+/// All the coefficients of degree less than `k` are left out of the partial result, so
+/// a larger `k` is a hint that widening the type is needed,
+/// rather than dropping a few high-degree terms.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_overflow_degree;
+///
+/// assert_eq!(horner_overflow_degree(7, &[2, 3, 4]), Ok(2 + 7 * (3 + 7 * 4)));
+///
+/// // x³ + 1 with x = 2²¹ overflows when computing x³ = x² * x
+/// assert_eq!(horner_overflow_degree(1 << 21, &[1, 0, 0, 1]), Err(0));
+/// ```
+pub fn horner_overflow_degree(x: i64, coeffs: &[i64]) -> Result<i64, usize> {
+    let mut acc: i64 = 0;
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        acc = acc.checked_mul(x).and_then(|p| p.checked_add(c)).ok_or(k)?;
+    }
+    Ok(acc)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// checking every operation for overflow.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `None` if any intermediate multiplication or addition overflows, even if the exact
+/// value of the polynomial would fit in `T`. Use [`horner_overflow_degree`] to know where
+/// an `i64` evaluation overflows.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::checked_horner;
+///
+/// assert_eq!(checked_horner(7, &[2, 3, 4]), Some(2 + 7 * (3 + 7 * 4)));
+/// assert_eq!(checked_horner(7, &[] as &[i32]), Some(0));
+///
+/// // 1 + x² with x = 2¹⁶ doesn't fit in `i32`
+/// assert_eq!(checked_horner(1 << 16, &[1, 0, 1]), None::<i32>);
+/// ```
+pub fn checked_horner<T: CheckedMul + CheckedAdd + Zero>(x: T, coeffs: &[T]) -> Option<T> {
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc.checked_mul(&x)?.checked_add(c)?;
+    }
+    Some(acc)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// wrapping around on overflow.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every multiplication and addition wraps around at the boundary of the type, regardless of
+/// whether debug assertions are enabled. Since wrapping arithmetic is arithmetic modulo `2ᵇⁱᵗˢ`,
+/// the result is the exact value of the polynomial reduced modulo `2ᵇⁱᵗˢ`
+/// (represented in two's complement for signed types),
+/// which makes it suitable for hashes and modular computations.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::wrapping_horner;
+///
+/// assert_eq!(wrapping_horner(7, &[2, 3, 4]), 2 + 7 * (3 + 7 * 4));
+///
+/// // 1 + 16 x + x² = 1 + 256 + 256 = 513 ≡ 1 (mod 2⁸) at x = 16
+/// assert_eq!(wrapping_horner(16u8, &[1, 16, 1]), 1);
+/// ```
+pub fn wrapping_horner<T: WrappingMul + WrappingAdd + Zero>(x: T, coeffs: &[T]) -> T {
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc.wrapping_mul(&x).wrapping_add(c);
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// saturating at the bounds of the type on overflow.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every multiplication and addition is clamped to the range of `T` instead of wrapping around
+/// or panicking, as is customary in fixed-point signal processing. Note that the clamping
+/// happens at each step, so after an intermediate saturation the result is in general
+/// not the saturated exact value: the subsequent steps keep operating on the clamped accumulator.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::saturating_horner;
+///
+/// assert_eq!(saturating_horner(7, &[2, 3, 4]), 2 + 7 * (3 + 7 * 4));
+///
+/// assert_eq!(saturating_horner(16i8, &[1, 1, 1]), i8::MAX);
+/// // the accumulator saturates at -128, then -128 + 100 = -28
+/// assert_eq!(saturating_horner(-16i8, &[100, 0, -1]), -28);
+/// ```
+pub fn saturating_horner<T: SaturatingMul + SaturatingAdd + Zero>(x: T, coeffs: &[T]) -> T {
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc.saturating_mul(&x).saturating_add(c);
+    }
+    acc
+}
+
+/// Evaluate a polynomial with 0/1 coefficients with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest, with `true` standing for `1`
+/// and `false` for `0`.
+///
+/// Returns `∑ bits[k] xᵏ`, such as the generating polynomial of a set of indicators, without
+/// converting the bits to integers first; the additions of the zero coefficients are skipped.
+/// The value is accumulated in `i128` to make overflow less likely; an overflow panics when debug
+/// assertions are enabled (reported at the location of the call) and wraps around otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_bits;
+///
+/// // 1 + x² + x³
+/// let bits = [true, false, true, true];
+///
+/// assert_eq!(horner_bits(10, &bits), 1101);
+/// assert_eq!(horner_bits(-2, &bits), 1 + 4 - 8);
+/// ```
+#[track_caller]
+pub fn horner_bits(x: i64, bits: &[bool]) -> i128 {
+    let x = x as i128;
+    let mut acc: i128 = 0;
+    for &bit in bits.iter().rev() {
+        acc *= x;
+        if bit {
+            acc += 1;
+        }
+    }
+    acc
+}
+
+/// Compute the polynomial hash of a sequence of `u32` words with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The words are the coefficients of the polynomial, listed from zeroth order to highest.
+///
+/// Returns `(data[0] + data[1] x + ... + data[n] xⁿ) mod modulus`, reducing after every step and
+/// using `u128` intermediates, so no operation overflows for any `modulus`.
+/// This is the primitive of [Rabin–Karp](https://en.wikipedia.org/wiki/Rabin%E2%80%93Karp_algorithm)
+/// string search and of polynomial rolling hashes.
+///
+/// To keep collisions unlikely the modulus should be a large prime, such as the Mersenne prime
+/// `2⁶¹ - 1`, and the base `x` should be chosen at random in `2..modulus`; in particular,
+/// it should not be smaller than the number of distinct words.
+///
+/// # Panics
+///
+/// Panics if `modulus` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_hash;
+///
+/// assert_eq!(horner_hash(10, &[3, 2, 1], 1_000), 123);
+/// assert_eq!(horner_hash(10, &[3, 2, 1], 100), 23);
+/// ```
+#[track_caller]
+pub fn horner_hash(x: u64, data: &[u32], modulus: u64) -> u64 {
+    assert!(modulus != 0, "the modulus must be nonzero");
+    let (x, m) = (x as u128, modulus as u128);
+    data.iter()
+        .rfold(0, |acc: u128, &c| (acc * x + c as u128) % m) as u64
+}
+
+/// Evaluate a polynomial modulo `m` with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `p(x) mod m`. The accumulator is reduced after every multiplication and every addition,
+/// using `u128` intermediates, so no operation overflows for any `x`, coefficients and `m`; neither
+/// `x` nor the coefficients need to be reduced beforehand. For `m == 1` the result is always zero.
+///
+/// # Panics
+///
+/// Panics if `m` is zero.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_mod;
+///
+/// // 2 + 3*5 + 4*5² = 117 = 5 (mod 7)
+/// assert_eq!(horner_mod(5, &[2, 3, 4], 7), 5);
+/// assert_eq!(horner_mod(5, &[2, 3, 4], 1), 0);
+///
+/// // no overflow with huge operands
+/// assert_eq!(horner_mod(u64::MAX, &[0, u64::MAX], u64::MAX - 1), 1);
+/// ```
+#[track_caller]
+pub fn horner_mod(x: u64, coeffs: &[u64], m: u64) -> u64 {
+    assert!(m != 0, "the modulus must be nonzero");
+    let m = m as u128;
+    let x = x as u128 % m;
+    let mut acc: u128 = 0;
+    for &c in coeffs.iter().rev() {
+        acc = acc * x % m;
+        acc = (acc + c as u128) % m;
+    }
+    acc as u64
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_overflow_degree() {
+        use super::horner_overflow_degree;
+        use crate::horner;
+        for x in -32..32 {
+            assert_eq!(horner_overflow_degree(x, &[]), Ok(0));
+            let coeffs = [1, -2, 3, -4, 5];
+            assert_eq!(horner_overflow_degree(x, &coeffs), Ok(horner(x, &coeffs)));
+        }
+        // 2ᵏ overflows at the 63rd power
+        let mut coeffs = [0; 70];
+        coeffs[69] = 1;
+        assert_eq!(horner_overflow_degree(2, &coeffs[..63]), Ok(0));
+        for n in 63..70 {
+            coeffs[n] = 1;
+            assert_eq!(horner_overflow_degree(2, &coeffs[..=n]), Err(n - 63));
+            coeffs[n] = 0;
+        }
+        // (-2)⁶³ = i64::MIN doesn't overflow
+        let mut coeffs = [0; 64];
+        coeffs[63] = 1;
+        assert_eq!(horner_overflow_degree(-2, &coeffs), Ok(i64::MIN));
+        // overflow in the addition
+        assert_eq!(horner_overflow_degree(1, &[1, i64::MAX, 0]), Err(0));
+        assert_eq!(horner_overflow_degree(1, &[0, i64::MAX, 1]), Err(1));
+        assert_eq!(
+            horner_overflow_degree(0, &[i64::MIN, i64::MAX, 1]),
+            Ok(i64::MIN)
+        );
+    }
+
+    #[test]
+    fn test_checked_horner() {
+        use super::checked_horner;
+        use crate::horner;
+        for x in -32..32 {
+            assert_eq!(checked_horner(x, &[]), Some(0));
+            let coeffs = [1, -2, 3, -4, 5];
+            assert_eq!(checked_horner(x, &coeffs), Some(horner(x, &coeffs)));
+        }
+        // 3¹⁹ fits in `i32`, 3²⁰ doesn't
+        let mut coeffs = [0i32; 21];
+        coeffs[19] = 1;
+        assert_eq!(checked_horner(3, &coeffs[..20]), Some(3i32.pow(19)));
+        assert_eq!(checked_horner(-3, &coeffs[..20]), Some(-(3i32.pow(19))));
+        coeffs[20] = 1;
+        assert_eq!(checked_horner(3, &coeffs), None);
+        assert_eq!(checked_horner(-3, &coeffs), None);
+        // large `x` overflows already at low degree
+        assert_eq!(checked_horner(50_000, &[1, 1, 1]), None);
+        assert_eq!(checked_horner(46_340, &[0, 0, 1]), Some(46_340 * 46_340));
+        // overflow in the addition
+        assert_eq!(checked_horner(1, &[1, i32::MAX]), None);
+        assert_eq!(checked_horner(1, &[-1, i32::MIN]), None);
+        assert_eq!(checked_horner(1, &[-1, i32::MAX]), Some(i32::MAX - 1));
+        // an overflowing intermediate is reported even if the value would fit
+        assert_eq!(checked_horner(2, &[i32::MIN, 1 << 30]), None);
+        assert_eq!(checked_horner(2, &[i32::MIN, 1 << 29]), Some(-(1 << 30)));
+        assert_eq!(checked_horner(2u8, &[0, 128]), None);
+    }
+
+    #[test]
+    fn test_wrapping_horner() {
+        use super::wrapping_horner;
+        use crate::horner;
+        // exhaustively on `u8`, against the exact value reduced modulo 2⁸
+        let coeffs = [200u8, 17, 255, 3, 128];
+        for x in 0..=u8::MAX {
+            let exact = horner(x as u64, &coeffs.map(u64::from));
+            assert_eq!(wrapping_horner(x, &coeffs), (exact % 256) as u8);
+        }
+        assert_eq!(wrapping_horner(3u8, &[]), 0);
+        // on `i16`, against the exact value reduced modulo 2¹⁶ in two's complement
+        let coeffs = [-30_000i16, 12_345, -1, 32_767, -32_768, 7];
+        for x in -300..300 {
+            let exact = horner(x as i128, &coeffs.map(i128::from));
+            assert_eq!(
+                wrapping_horner(x, &coeffs),
+                exact.rem_euclid(1 << 16) as u16 as i16
+            );
+        }
+        // no wrapping: the same as plain Horner
+        for x in -8..8 {
+            let coeffs = [1i16, -2, 3, -4];
+            assert_eq!(wrapping_horner(x, &coeffs), horner(x, &coeffs));
+        }
+    }
+
+    #[test]
+    fn test_saturating_horner() {
+        use super::saturating_horner;
+        use crate::horner;
+        assert_eq!(saturating_horner(3i8, &[]), 0);
+        // no saturation: the same as plain Horner
+        for x in -4..4 {
+            let coeffs = [1i8, -2, 3];
+            assert_eq!(saturating_horner(x, &coeffs), horner(x, &coeffs));
+        }
+        // exhaustively on `i8`, against an evaluation clamping every step in `i32`
+        let clamp = |v: i32| v.clamp(i8::MIN.into(), i8::MAX.into());
+        let coeffs = [-100i8, 50, 3, -7];
+        for x in i8::MIN..=i8::MAX {
+            let expected = coeffs
+                .iter()
+                .rev()
+                .fold(0, |acc, &c| clamp(clamp(acc * i32::from(x)) + i32::from(c)));
+            assert_eq!(i32::from(saturating_horner(x, &coeffs)), expected);
+        }
+        // saturation at both bounds
+        assert_eq!(saturating_horner(100i8, &[0, 2]), i8::MAX);
+        assert_eq!(saturating_horner(-100i8, &[0, 2]), i8::MIN);
+        assert_eq!(saturating_horner(1i8, &[100, 100]), i8::MAX);
+        assert_eq!(saturating_horner(1i8, &[-100, -100]), i8::MIN);
+        assert_eq!(saturating_horner(-2i8, &[0, 0, 0, 0, 0, 0, 0, -1]), i8::MAX);
+        assert_eq!(saturating_horner(-2i8, &[0, 0, 0, 0, 0, 0, 0, 1]), i8::MIN);
+        // and recovery from it
+        assert_eq!(saturating_horner(16i8, &[-100, 1, 1]), 27);
+    }
+
+    #[test]
+    fn test_horner_hash() {
+        use super::horner_hash;
+        use crate::horner;
+        const M: u64 = (1 << 61) - 1;
+        assert_eq!(horner_hash(3, &[], M), 0);
+        assert_eq!(horner_hash(3, &[5], 3), 2);
+        assert_eq!(horner_hash(3, &[1, 2, 3], 1), 0);
+        for x in 0..16 {
+            let coeffs = [1, 2, 3, 4, 5];
+            assert_eq!(
+                horner_hash(x, &coeffs, M),
+                horner(x, &coeffs.map(u64::from))
+            );
+        }
+        // no overflow for huge bases, words and moduli
+        let x = u64::MAX - 1;
+        let expected = ((x as u128 * u32::MAX as u128 + 1) % u64::MAX as u128) as u64;
+        assert_eq!(horner_hash(x, &[1, u32::MAX], u64::MAX), expected);
+        // rolling hash of the windows of length `w`:
+        // h(dᵢ, ..., dᵢ₊w₋₁) = dᵢ + x (h(dᵢ₊₁, ..., dᵢ₊w) - dᵢ₊w xʷ⁻¹)
+        let data: [u32; 20] = core::array::from_fn(|k| ((k * 7) % 5) as u32 * 1_000_003);
+        let (x, w) = (0x1234_5678_9abc, 6);
+        let top = horner_hash(x, &[0, 0, 0, 0, 0, 1], M) as u128;
+        let (x128, m128) = (x as u128, M as u128);
+        for i in 0..data.len() - w {
+            let next = horner_hash(x, &data[i + 1..i + 1 + w], M) as u128;
+            let removed = (m128 - data[i + w] as u128 * top % m128) % m128;
+            let rolled = (data[i] as u128 + x128 * ((next + removed) % m128)) % m128;
+            assert_eq!(horner_hash(x, &data[i..i + w], M) as u128, rolled);
+        }
+        // the data is periodic with period 5, so identical windows hash equal
+        for i in 0..data.len() - w - 5 {
+            assert_eq!(
+                horner_hash(x, &data[i..i + w], M),
+                horner_hash(x, &data[i + 5..i + 5 + w], M)
+            );
+            assert_ne!(
+                horner_hash(x, &data[i..i + w], M),
+                horner_hash(x, &data[i + 1..i + 1 + w], M)
+            );
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the modulus must be nonzero")]
+    fn test_horner_hash_zero_modulus() {
+        use super::horner_hash;
+        horner_hash(2, &[1, 2, 3], 0);
+    }
+
+    #[test]
+    fn test_horner_mod() {
+        use super::horner_mod;
+        use crate::horner;
+        assert_eq!(horner_mod(3, &[], 5), 0);
+        for x in 0..16 {
+            let coeffs = [1, 2, 3, 4, 5];
+            for m in [1, 2, 7, 1_000, u64::MAX] {
+                assert_eq!(horner_mod(x, &coeffs, m), horner(x, &coeffs) % m);
+            }
+            assert_eq!(horner_mod(x, &coeffs, 1), 0);
+        }
+        // unreduced operands
+        assert_eq!(horner_mod(12, &[9, 8], 5), (9 + 12 * 8) % 5);
+        assert_eq!(horner_mod(u64::MAX, &[u64::MAX; 8], u64::MAX), 0);
+        assert_eq!(horner_mod(u64::MAX, &[u64::MAX; 8], 2), 0);
+        assert_eq!(horner_mod(u64::MAX, &[u64::MAX; 7], 2), 1);
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_horner_mod_bigint() {
+        use super::horner_mod;
+        use num_bigint::BigUint;
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state
+        };
+        for n in 0..32 {
+            let x = next();
+            let coeffs: [u64; 32] = core::array::from_fn(|_| next());
+            let coeffs = &coeffs[..n];
+            // moduli of every size, including small ones
+            let m = next() >> (n * 2) | 1;
+            let exact = coeffs
+                .iter()
+                .rfold(BigUint::from(0u8), |acc, &c| acc * x + c);
+            assert_eq!(BigUint::from(horner_mod(x, coeffs, m)), exact % m);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the modulus must be nonzero")]
+    fn test_horner_mod_zero_modulus() {
+        use super::horner_mod;
+        horner_mod(2, &[1, 2, 3], 0);
+    }
+
+    #[test]
+    fn test_horner_bits() {
+        use super::horner_bits;
+        use crate::horner;
+        for x in -16..16 {
+            assert_eq!(horner_bits(x, &[]), 0);
+            for mask in 0u32..1 << 10 {
+                let bits: [bool; 10] = core::array::from_fn(|k| mask & (1 << k) != 0);
+                assert_eq!(
+                    horner_bits(x, &bits),
+                    horner(x as i128, &bits.map(i128::from))
+                );
+            }
+        }
+        // beyond the range of `i64`
+        let mut bits = [false; 64];
+        bits[63] = true;
+        bits[0] = true;
+        assert_eq!(horner_bits(2, &bits), (1 << 63) + 1);
+        assert_eq!(horner_bits(-2, &bits), 1 - (1 << 63));
+    }
+}
@@ -0,0 +1,250 @@
+//! Evaluation with interval arithmetic.
+
+use crate::eft::{add_down, add_up, mul_down, mul_up};
+
+/// Multiplies the intervals `[a, b]` and `[c, d]` with outward rounding.
+#[inline]
+fn mul_interval((a, b): (f64, f64), (c, d): (f64, f64)) -> (f64, f64) {
+    let lo = mul_down(a, c)
+        .min(mul_down(a, d))
+        .min(mul_down(b, c))
+        .min(mul_down(b, d));
+    let hi = mul_up(a, c)
+        .max(mul_up(a, d))
+        .max(mul_up(b, c))
+        .max(mul_up(b, d));
+    (lo, hi)
+}
+
+/// Interval type with outward-rounded arithmetic, for [`horner_interval`].
+///
+/// Implementations must guarantee that the result of every operation contains the exact result
+/// of the same operation on any pair of points of the operands, usually by rounding the lower
+/// endpoint downward and the upper endpoint upward.
+///
+/// The crate implements it for `(f64, f64)`, representing the interval `[lo, hi]`, with directed
+/// rounding emulated in software as in [`horner_interval_soft`]. It can be implemented for the
+/// interval types of other crates, wrapped in a newtype.
+#[cfg(feature = "interval")]
+pub trait IntervalLike: Sized {
+    /// Returns the degenerate interval `[0, 0]`.
+    fn zero() -> Self;
+    /// Returns an enclosure of the sum of `self` and `other`.
+    fn add_outward(&self, other: &Self) -> Self;
+    /// Returns an enclosure of the product of `self` and `other`.
+    fn mul_outward(&self, other: &Self) -> Self;
+}
+
+#[cfg(feature = "interval")]
+impl IntervalLike for (f64, f64) {
+    fn zero() -> Self {
+        (0.0, 0.0)
+    }
+
+    fn add_outward(&self, other: &Self) -> Self {
+        (add_down(self.0, other.0), add_up(self.1, other.1))
+    }
+
+    fn mul_outward(&self, other: &Self) -> Self {
+        mul_interval(*self, *other)
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// with interval arithmetic.
+///
+/// The coefficients are listed from zeroth order to highest, and they are intervals too.
+///
+/// Every step of the scheme is performed with the outward-rounded operations of [`IntervalLike`],
+/// hence the result is guaranteed to contain `p(x)` for every point `x` of the interval
+/// and every choice of coefficients in their intervals.
+///
+/// As usual with interval arithmetic the enclosure may overestimate the true range of the polynomial,
+/// especially on wide intervals.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_interval;
+///
+/// let coeffs = [(0.2, 0.2), (0.3, 0.3), (0.4, 0.4)];
+/// let (lo, hi) = horner_interval((0.1, 0.1), &coeffs);
+/// let y = 0.2 + 0.1 * (0.3 + 0.1 * 0.4);
+///
+/// assert!(lo <= y && y <= hi);
+///
+/// // 1 + x² on [-1, 2] is contained in [-1, 5]
+/// assert_eq!(horner_interval((-1.0, 2.0), &[(1.0, 1.0), (0.0, 0.0), (1.0, 1.0)]), (-1.0, 5.0));
+/// ```
+#[cfg(feature = "interval")]
+pub fn horner_interval<I: IntervalLike>(x: I, coeffs: &[I]) -> I {
+    let mut acc = I::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc.mul_outward(&x).add_outward(c);
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// on the interval `[lo, hi]`, with outward rounding emulated in software.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns an interval `(lo, hi)` which is guaranteed to contain `p(x)` for every `x` in `[lo, hi]`.
+/// Every operation is performed with directed rounding, downward for the lower endpoint and
+/// upward for the upper endpoint, using error-free transformations instead of the rounding modes
+/// of the hardware; hence the enclosure is portable across platforms.
+///
+/// The price for this is a slowdown of roughly an order of magnitude compared to [`horner`](fn@crate::horner):
+/// every step performs four directed multiplications per endpoint, each costing a handful of
+/// floating point operations.
+///
+/// As usual with interval arithmetic the enclosure may overestimate the true range of the polynomial,
+/// especially on wide intervals.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_interval_soft;
+///
+/// let (lo, hi) = horner_interval_soft(0.1, 0.1, &[0.2, 0.3, 0.4]);
+/// let y = 0.2 + 0.1 * (0.3 + 0.1 * 0.4);
+///
+/// assert!(lo < hi);
+/// assert!(lo <= y && y <= hi);
+/// ```
+pub fn horner_interval_soft(lo: f64, hi: f64, coeffs: &[f64]) -> (f64, f64) {
+    coeffs.iter().rfold((0.0, 0.0), |acc, &c| {
+        let (a, b) = mul_interval(acc, (lo, hi));
+        (add_down(a, c), add_up(b, c))
+    })
+}
+
+/// Evaluate a Taylor model, i.e. a polynomial with a remainder interval, at the point `x`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The caller is responsible for providing a valid `remainder = (rlo, rhi)`, such that
+/// `rlo <= f(x) - p(x) <= rhi`, where `f` is the function approximated by the polynomial `p`:
+/// the result is guaranteed to contain `f(x)` only if the remainder is a valid bound.
+///
+/// The polynomial is evaluated as in [`horner_interval_soft`] on the degenerate interval `[x, x]`,
+/// so that the rounding errors of the evaluation are bounded rigorously, and then the resulting
+/// enclosure is widened by the remainder, again with outward rounding.
+/// Hence, like [`horner_interval_soft`], it requires the `softfloat` feature.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_taylor_model;
+///
+/// // exp(x) = 1 + x + x²/2 + R(x) with 0 <= R(x) <= 1/6 e^ξ x³ for x, ξ in [0, 1/4]
+/// let x = 0.25;
+/// let remainder = (0.0, 1.3 / 6.0 * x * x * x);
+/// let (lo, hi) = horner_taylor_model(x, &[1.0, 1.0, 0.5], remainder);
+///
+/// assert!(lo <= x.exp() && x.exp() <= hi);
+/// ```
+pub fn horner_taylor_model(x: f64, coeffs: &[f64], remainder: (f64, f64)) -> (f64, f64) {
+    let (lo, hi) = horner_interval_soft(x, x, coeffs);
+    (add_down(lo, remainder.0), add_up(hi, remainder.1))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "interval")]
+    #[test]
+    fn test_horner_interval() {
+        use super::{horner_interval, horner_interval_soft};
+        use crate::horner;
+        let point = |c: f64| (c, c);
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_interval(point(x), &[]), (0., 0.));
+            let coeffs = [1., 2., 3., 4., 5.];
+            let y = horner(x, &coeffs);
+            assert_eq!(horner_interval(point(x), &coeffs.map(point)), (y, y));
+        }
+        let coeffs = [0.1, -0.7, 0.3, 1.9, -0.5];
+        for (lo, hi) in [(-1., 1.), (0.1, 0.1), (0., 0.5), (-3., -2.), (-0.01, 10.)] {
+            let (ylo, yhi) = horner_interval((lo, hi), &coeffs.map(point));
+            assert_eq!((ylo, yhi), horner_interval_soft(lo, hi, &coeffs));
+            for i in 0..=100 {
+                let x = lo + (hi - lo) * i as f64 / 100.;
+                let y = horner(x, &coeffs);
+                assert!(ylo <= y && y <= yhi, "{y} not in [{ylo}, {yhi}]");
+            }
+        }
+        // 1 + c x with c in [1, 2] and x in [2, 3] is in [3, 7]
+        assert_eq!(horner_interval((2., 3.), &[point(1.), (1., 2.)]), (3., 7.));
+    }
+
+    #[test]
+    fn test_horner_interval_soft_point() {
+        use super::horner_interval_soft;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_interval_soft(x, x, &[]), (0., 0.));
+            assert_eq!(horner_interval_soft(x, x, &[1.]), (1., 1.));
+            let y = horner(x, &[1., 2., 3., 4., 5.]);
+            assert_eq!(horner_interval_soft(x, x, &[1., 2., 3., 4., 5.]), (y, y));
+        }
+        for x in -32..32 {
+            let x = x as f64 / 10.;
+            let coeffs = [0.1, -0.2, 0.3, -0.4, 0.5, -0.6];
+            let y = horner(x, &coeffs);
+            let (lo, hi) = horner_interval_soft(x, x, &coeffs);
+            assert!(lo <= y && y <= hi, "{y} not in [{lo}, {hi}]");
+            let bound = horner(x.abs(), &coeffs.map(f64::abs));
+            assert!(hi - lo <= 1e-14 * bound);
+        }
+    }
+
+    #[test]
+    fn test_horner_interval_soft_range() {
+        use super::horner_interval_soft;
+        use crate::horner;
+        let polys: [&[f64]; 4] = [
+            &[1., 2., 3.],
+            &[-1., 0., 1.],
+            &[0.1, -0.7, 0.3, 1.9, -0.5],
+            &[1. / 3., 2. / 3., -4. / 3., 1. / 7., 0., -1. / 9.],
+        ];
+        let intervals = [(-1., 1.), (0., 0.5), (-3., -2.), (0.1, 0.3), (-0.01, 10.)];
+        for coeffs in polys {
+            for (lo, hi) in intervals {
+                let (ylo, yhi) = horner_interval_soft(lo, hi, coeffs);
+                for i in 0..=100 {
+                    let x = lo + (hi - lo) * i as f64 / 100.;
+                    let y = horner(x, coeffs);
+                    assert!(ylo <= y && y <= yhi, "{y} not in [{ylo}, {yhi}]");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_horner_taylor_model() {
+        use super::{horner_interval_soft, horner_taylor_model};
+        // degree 5 Taylor polynomial of exp at 0,
+        // with remainder R(x) = e^ξ x⁶/6! in [0, e^(1/2) x⁶/6!] for x in [-1/2, 1/2]
+        let coeffs = [1., 1., 1. / 2., 1. / 6., 1. / 24., 1. / 120.];
+        let mut excluded = 0;
+        for k in -16..=16 {
+            let x = k as f64 / 32.;
+            let remainder = (0., 1.65 * x.powi(6) / 720.);
+            let (lo, hi) = horner_taylor_model(x, &coeffs, remainder);
+            let y = x.exp();
+            assert!(lo <= y && y <= hi, "{y} not in [{lo}, {hi}]");
+            assert!(hi - lo <= remainder.1 + 1e-15);
+            let (lo, hi) = horner_interval_soft(x, x, &coeffs);
+            if !(lo <= y && y <= hi) {
+                excluded += 1;
+            }
+        }
+        // without the remainder the enclosure is not guaranteed
+        assert!(excluded > 0);
+        assert_eq!(horner_taylor_model(3., &[], (-1., 2.)), (-1., 2.));
+    }
+}
@@ -0,0 +1,125 @@
+//! Evaluation with coefficients produced by an iterator.
+
+use core::ops::Mul;
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// taking the coefficients from an iterator.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Horner's method starts from the highest order, so the iterator is consumed from the back:
+/// it must be a [`DoubleEndedIterator`], like those over slices, ranges and vectors, and most
+/// of their adapters. For iterators which can only go forward use [`horner_desc_iter`],
+/// which takes the coefficients from highest order to zeroth.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_iter;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_iter(x, []), 0);
+/// assert_eq!(horner_iter(x, [2, 3, 4]), 2 + x * (3 + x * 4));
+/// // 1 + 2x + 3x² + 4x³, without collecting the coefficients
+/// assert_eq!(horner_iter(x, (0..4).map(|k| k + 1)), 1 + x * (2 + x * (3 + x * 4)));
+/// ```
+#[track_caller]
+pub fn horner_iter<T, I>(x: T, coeffs: I) -> T
+where
+    T: Zero,
+    T: for<'a> Mul<&'a T, Output = T>,
+    I: IntoIterator<Item = T>,
+    I::IntoIter: DoubleEndedIterator,
+{
+    let mut acc = T::zero();
+    for c in coeffs.into_iter().rev() {
+        acc = acc * &x + c;
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// taking the coefficients from an iterator.
+///
+/// The coefficients are listed from highest order to zeroth, i.e. the first one is the leading
+/// coefficient. The iterator is consumed front to back, so it needn't be double-ended.
+/// See also [`horner_iter`] and [`horner_desc`](fn@crate::horner_desc).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_desc_iter;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_desc_iter(x, [4, 3, 2]), 2 + x * (3 + x * 4));
+/// // the iterator is consumed lazily, it doesn't know its length in advance
+/// let mut k = 5;
+/// let coeffs = core::iter::from_fn(|| {
+///     k -= 1;
+///     (k > 0).then_some(k)
+/// });
+/// assert_eq!(horner_desc_iter(x, coeffs), 1 + x * (2 + x * (3 + x * 4)));
+/// ```
+#[track_caller]
+pub fn horner_desc_iter<T, I>(x: T, coeffs: I) -> T
+where
+    T: Zero,
+    T: for<'a> Mul<&'a T, Output = T>,
+    I: IntoIterator<Item = T>,
+{
+    let mut acc = T::zero();
+    for c in coeffs {
+        acc = acc * &x + c;
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_iter() {
+        use super::horner_iter;
+        use crate::horner;
+        for x in -16..16 {
+            assert_eq!(horner_iter(x, []), 0);
+            assert_eq!(horner_iter(x, [5]), 5);
+            let coeffs = [1, -2, 3, -4, 5, -6];
+            assert_eq!(horner_iter(x, coeffs), horner(x, &coeffs));
+            assert_eq!(horner_iter(x, coeffs.iter().copied()), horner(x, &coeffs));
+            assert_eq!(
+                horner_iter(x, (0..6).map(|k| (k + 1) * if k % 2 == 0 { 1 } else { -1 })),
+                horner(x, &coeffs)
+            );
+            let x = x as f64 / 4.;
+            assert_eq!(
+                horner_iter(x, (0..5).map(|k| 1. / (k + 1) as f64)),
+                horner(x, &[1., 0.5, 1. / 3., 0.25, 0.2])
+            );
+        }
+    }
+
+    #[test]
+    fn test_horner_desc_iter() {
+        use super::horner_desc_iter;
+        use crate::horner;
+        for x in -16..16 {
+            assert_eq!(horner_desc_iter(x, []), 0);
+            assert_eq!(horner_desc_iter(x, [5]), 5);
+            let coeffs = [1, -2, 3, -4, 5, -6];
+            assert_eq!(
+                horner_desc_iter(x, coeffs.iter().rev().copied()),
+                horner(x, &coeffs)
+            );
+            // forward-only iterator
+            let mut k = 0;
+            let leading_first = core::iter::from_fn(|| {
+                k += 1;
+                (k <= 6).then(|| coeffs[6 - k])
+            });
+            assert_eq!(horner_desc_iter(x, leading_first), horner(x, &coeffs));
+        }
+    }
+}
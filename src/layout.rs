@@ -0,0 +1,165 @@
+//! Evaluation of coefficients stored in layouts other than a contiguous slice.
+
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// reading the coefficients from a ring buffer.
+///
+/// The buffer is logically rotated so that `buf[head]` is the highest order coefficient:
+/// the coefficients are listed in ascending order starting right after `head`
+/// and wrapping around, i.e. `buf[head+1], ..., buf[len-1], buf[0], ..., buf[head]`.
+///
+/// This evaluates the coefficients of an online filter backed by a fixed ring buffer
+/// without physically rotating it.
+///
+/// # Panics
+///
+/// Panics if `buf` is not empty and `head >= buf.len()`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_ring_slice;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_ring_slice(x, &[], 0), 0);
+///
+/// assert_eq!(
+///     //  highest --v  v-- lowest
+///     horner_ring_slice(x, &[3, 4, 2], 1),
+///     2 + x * (3 + x * 4)
+/// );
+/// ```
+pub fn horner_ring_slice<T>(x: T, buf: &[T], head: usize) -> T
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    if buf.is_empty() {
+        return T::zero();
+    }
+    assert!(
+        head < buf.len(),
+        "head out of bounds: the length is {} but the head is {head}",
+        buf.len()
+    );
+    let (oldest, newest) = buf.split_at(head + 1);
+    newest
+        .iter()
+        .chain(oldest)
+        .rfold(T::zero(), |acc: T, c: &T| acc * &x + c)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// looking up the coefficients in a codebook.
+///
+/// The coefficients are `codebook[indices[0]], codebook[indices[1]], ...`,
+/// listed from zeroth order to highest.
+///
+/// Each coefficient is dequantized during the fold, without materializing an intermediate vector.
+/// The price is one bounds check per step, which is well predicted and usually negligible
+/// compared to the multiply-add.
+///
+/// See [`try_horner_codebook`] for a non-panicking version.
+///
+/// # Panics
+///
+/// Panics if any index is out of bounds for `codebook`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_codebook;
+///
+/// let x = 7.0;
+/// let codebook = [2.0, 3.0, 4.0];
+///
+/// assert_eq!(
+///     horner_codebook(x, &[0, 0, 2, 1], &codebook),
+///     2.0 + x * (2.0 + x * (4.0 + x * 3.0))
+/// );
+/// ```
+pub fn horner_codebook(x: f64, indices: &[u8], codebook: &[f64]) -> f64 {
+    indices
+        .iter()
+        .rfold(0.0, |acc, &i| acc * x + codebook[i as usize])
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// looking up the coefficients in a codebook.
+///
+/// Same as [`horner_codebook`], but returns `None` if any index is out of bounds for `codebook`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::try_horner_codebook;
+///
+/// let x = 7.0;
+/// let codebook = [2.0, 3.0, 4.0];
+///
+/// assert_eq!(try_horner_codebook(x, &[2, 1], &codebook), Some(4.0 + x * 3.0));
+/// assert_eq!(try_horner_codebook(x, &[2, 3], &codebook), None);
+/// ```
+pub fn try_horner_codebook(x: f64, indices: &[u8], codebook: &[f64]) -> Option<f64> {
+    indices
+        .iter()
+        .try_rfold(0.0, |acc, &i| Some(acc * x + codebook.get(i as usize)?))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_ring_slice() {
+        use super::horner_ring_slice;
+        use crate::horner;
+        let buf = [1, 2, 3, 4, 5, 6, 7];
+        for x in -8..8 {
+            assert_eq!(horner_ring_slice(x, &[], 0), 0);
+            assert_eq!(horner_ring_slice(x, &[3], 0), 3);
+            for head in 0..buf.len() {
+                let mut rotated = buf.to_vec();
+                rotated.rotate_left(head + 1);
+                assert_eq!(rotated.last(), Some(&buf[head]));
+                assert_eq!(horner_ring_slice(x, &buf, head), horner(x, &rotated));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "head out of bounds")]
+    fn test_horner_ring_slice_out_of_bounds() {
+        use super::horner_ring_slice;
+        horner_ring_slice(2, &[1, 2, 3], 3);
+    }
+
+    #[test]
+    fn test_horner_codebook() {
+        use super::{horner_codebook, try_horner_codebook};
+        use crate::horner;
+        let codebook = [-1.5, 0., 0.25, 2., 3.];
+        let indices = [4, 0, 0, 2, 1, 3, 4, 2];
+        let dequantized: Vec<f64> = indices.iter().map(|&i| codebook[i as usize]).collect();
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            assert_eq!(horner_codebook(x, &[], &codebook), 0.);
+            assert_eq!(try_horner_codebook(x, &[], &[]), Some(0.));
+            for n in 0..=indices.len() {
+                let y = horner(x, &dequantized[..n]);
+                assert_eq!(horner_codebook(x, &indices[..n], &codebook), y);
+                assert_eq!(try_horner_codebook(x, &indices[..n], &codebook), Some(y));
+            }
+            assert_eq!(try_horner_codebook(x, &[0, 5, 1], &codebook), None);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "index out of bounds")]
+    fn test_horner_codebook_out_of_bounds() {
+        use super::horner_codebook;
+        horner_codebook(2., &[0, 1, 2], &[1., 2.]);
+    }
+}
@@ -18,10 +18,172 @@
 
 #![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
+#![cfg_attr(feature = "simd", feature(portable_simd))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 use core::ops::{Add, Mul};
 use num_traits::{MulAdd, Zero};
 
+mod accumulator;
+#[cfg(feature = "autodiff")]
+mod autodiff;
+#[cfg(feature = "alloc")]
+mod batch;
+#[cfg(feature = "alloc")]
+mod bernstein;
+#[cfg(feature = "num-bigint")]
+mod bigint;
+mod bivariate;
+mod bound;
+mod compensated;
+mod complex;
+mod const_eval;
+mod deriv;
+#[cfg(feature = "std")]
+mod dispatch;
+#[cfg(feature = "alloc")]
+mod display;
+mod division;
+mod eft;
+mod estrin;
+#[cfg(feature = "alloc")]
+mod evaluator;
+#[cfg(feature = "field")]
+mod field;
+#[cfg(feature = "fixed")]
+mod fixed;
+#[cfg(feature = "glam")]
+mod glam;
+mod integer;
+#[cfg(feature = "softfloat")]
+mod interval;
+mod iter;
+mod layout;
+mod matrix;
+mod nan;
+mod order;
+mod orthogonal;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "alloc")]
+mod paterson_stockmeyer;
+#[cfg(feature = "alloc")]
+mod polynomial;
+mod prefetch;
+mod rational;
+#[cfg(feature = "serde")]
+mod record;
+mod refine;
+#[cfg(feature = "rug")]
+mod rug;
+mod scaled;
+#[cfg(feature = "alloc")]
+mod sign;
+#[cfg(feature = "simd")]
+mod simd;
+mod split;
+#[cfg(feature = "futures")]
+mod stream;
+mod table;
+#[cfg(any(feature = "unroll-4", feature = "unroll-8", feature = "unroll-16"))]
+mod unroll;
+
+pub use accumulator::{horner_acc, horner_kahan, Accumulator, Compensated, Kahan, Plain};
+#[cfg(feature = "autodiff")]
+pub use autodiff::HyperDual;
+#[cfg(feature = "alloc")]
+pub use batch::{
+    horner_batch_points_cached, horner_binned, horner_binned_with_overflow, horner_multi_into,
+};
+#[cfg(feature = "alloc")]
+pub use bernstein::de_casteljau;
+#[cfg(feature = "num-bigint")]
+pub use bigint::horner_bigint;
+pub use bivariate::horner_2d;
+pub use bound::horner_with_bound;
+pub use compensated::{
+    horner_adaptive, horner_compensated, horner_compensated_f32, horner_exactness,
+    horner_last_compensated, horner_round_modes,
+};
+#[cfg(all(
+    feature = "num-complex",
+    feature = "alloc",
+    any(feature = "std", feature = "libm")
+))]
+pub use complex::freq_response;
+#[cfg(feature = "num-complex")]
+pub use complex::horner_gaussian;
+pub use complex::horner_real_at_complex;
+pub use const_eval::{horner_f32, horner_f64};
+#[cfg(feature = "alloc")]
+pub use deriv::{derivative, integrate};
+pub use deriv::{
+    horner_deriv, horner_derivatives, horner_jet2, horner_newton_step, integrate_definite,
+    newton_refine, newton_refine_tol, safe_step, Jet2,
+};
+#[cfg(feature = "std")]
+pub use dispatch::horner_auto;
+#[cfg(feature = "alloc")]
+pub use display::horner_debug_string;
+#[cfg(feature = "alloc")]
+pub use division::deflate;
+pub use division::horner_table;
+#[cfg(feature = "alloc")]
+pub use estrin::{estrin, estrin_fma};
+pub use estrin::{estrin_array, estrin_iterative, estrin_many};
+#[cfg(feature = "alloc")]
+pub use evaluator::make_evaluator;
+#[cfg(feature = "field")]
+pub use field::{horner_field, horner_semiring, Field, Semiring};
+#[cfg(feature = "fixed")]
+pub use fixed::{horner_fixed, Overflow};
+#[cfg(feature = "glam")]
+pub use glam::{horner_vec3, horner_vec4};
+pub use integer::{
+    checked_horner, horner_bits, horner_hash, horner_mod, horner_overflow_degree,
+    saturating_horner, wrapping_horner,
+};
+#[cfg(feature = "interval")]
+pub use interval::{horner_interval, IntervalLike};
+#[cfg(feature = "softfloat")]
+pub use interval::{horner_interval_soft, horner_taylor_model};
+pub use iter::{horner_desc_iter, horner_iter};
+pub use layout::{horner_codebook, horner_ring_slice, try_horner_codebook};
+pub use matrix::{horner_matrix, SquareMatrix};
+pub use nan::{horner_checked_finite, horner_nan_payload, NonFiniteError};
+pub use order::{horner_checked_order, horner_desc, horner_reciprocal};
+pub use orthogonal::{chebyshev_eval, clenshaw, hermite_eval, legendre_eval};
+#[cfg(feature = "alloc")]
+pub use orthogonal::{chebyshev_to_monomial, monomial_to_chebyshev};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use orthogonal::{goertzel_cosine, goertzel_sine};
+#[cfg(feature = "rayon")]
+pub use par::{horner_2d_grid_par, horner_par};
+#[cfg(feature = "alloc")]
+pub use paterson_stockmeyer::paterson_stockmeyer;
+#[cfg(feature = "alloc")]
+pub use polynomial::Polynomial;
+pub use prefetch::horner_prefetch;
+pub use rational::{eval_rational, eval_rational_inv};
+#[cfg(feature = "serde")]
+pub use record::{horner_record, EvalRecord};
+pub use refine::{horner_refine, horner_update};
+#[cfg(feature = "rug")]
+pub use rug::horner_rug;
+pub use scaled::horner_logspace;
+#[cfg(feature = "alloc")]
+pub use sign::horner_sign_only;
+#[cfg(feature = "simd")]
+pub use simd::{
+    horner_gather, horner_masked, horner_simd_f32x8, horner_simd_f64x4, horner_simd_single,
+};
+pub use split::{horner_2way, horner_even_odd, horner_kway};
+#[cfg(feature = "futures")]
+pub use stream::horner_stream;
+pub use table::fill_coeffs;
+
 #[inline]
 #[doc(hidden)]
 pub fn mul_add<T: MulAdd<Output = T>>(x: T, a: T, b: T) -> T {
@@ -47,13 +209,32 @@ pub fn mul_add<T: MulAdd<Output = T>>(x: T, a: T, b: T) -> T {
 ///     2 + x * (3 + x * 4)
 /// );
 /// ```
+///
+/// If the arithmetic panics, for instance because of an integer overflow with debug assertions
+/// enabled, the panic is reported at the location of the call.
+///
+/// With the feature `unroll-4`, `unroll-8` or `unroll-16`, polynomials with up to 4, 8 or 16
+/// coefficients respectively are evaluated with straight-line code instead of a loop, which may
+/// generate better code when the compiler doesn't unroll the loop by itself. The operations and
+/// the results are exactly the same; higher levels produce larger binaries, since all the unrolled
+/// variants are instantiated for every type `T`. If several levels are enabled, the highest wins.
+#[track_caller]
 pub fn horner<T>(x: T, coeffs: &[T]) -> T
 where
     T: Zero,
     T: for<'a> Add<&'a T, Output = T>,
     T: for<'a> Mul<&'a T, Output = T>,
 {
-    coeffs.iter().rfold(T::zero(), |acc: T, c: &T| acc * &x + c)
+    #[cfg(any(feature = "unroll-4", feature = "unroll-8", feature = "unroll-16"))]
+    if let Some(value) = unroll::horner_unrolled(&x, coeffs) {
+        return value;
+    }
+    // a loop rather than `rfold`, because the location of the caller isn't tracked through closures
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc * &x + c;
+    }
+    acc
 }
 
 /// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
@@ -78,13 +259,321 @@ where
 ///     2 + x * (3 + x * 4)
 /// );
 /// ```
+#[track_caller]
 pub fn horner_array<T, const N: usize>(x: T, coeffs: &[T; N]) -> T
 where
     T: Zero,
     T: for<'a> Add<&'a T, Output = T>,
     T: for<'a> Mul<&'a T, Output = T>,
 {
-    coeffs.iter().rfold(T::zero(), |acc: T, c: &T| acc * &x + c)
+    horner(x, coeffs)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using operators by value.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner`](fn@horner), but it requires only `T: Add<T>` and `T: Mul<T>`, cloning `x`
+/// and the coefficients at every step, instead of the operators with a reference on the right.
+/// This fits types which implement only the by-value operators, as is common for user-defined
+/// numeric types, e.g. dual numbers for forward-mode automatic differentiation.
+///
+/// # Examples
+///
+/// ```
+/// use core::ops::{Add, Mul};
+/// use num_traits::Zero;
+/// use polyeval::horner_val;
+///
+/// // dual number a + bε with ε² = 0
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Dual(f64, f64);
+///
+/// impl Add for Dual {
+///     type Output = Dual;
+///     fn add(self, rhs: Dual) -> Dual {
+///         Dual(self.0 + rhs.0, self.1 + rhs.1)
+///     }
+/// }
+/// impl Mul for Dual {
+///     type Output = Dual;
+///     fn mul(self, rhs: Dual) -> Dual {
+///         Dual(self.0 * rhs.0, self.0 * rhs.1 + self.1 * rhs.0)
+///     }
+/// }
+/// impl Zero for Dual {
+///     fn zero() -> Dual {
+///         Dual(0.0, 0.0)
+///     }
+///     fn is_zero(&self) -> bool {
+///         *self == Dual(0.0, 0.0)
+///     }
+/// }
+///
+/// // p(x) = 2 + 3x + 4x², p'(x) = 3 + 8x
+/// let coeffs = [Dual(2.0, 0.0), Dual(3.0, 0.0), Dual(4.0, 0.0)];
+/// assert_eq!(horner_val(Dual(7.0, 1.0), &coeffs), Dual(219.0, 59.0));
+/// ```
+#[track_caller]
+pub fn horner_val<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+{
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc * x.clone() + c.clone();
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// skipping the zero coefficients of highest order.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner`](fn@horner), but the trailing zeros of `coeffs` (the coefficients of highest
+/// order) are skipped with [`Zero::is_zero`] before the evaluation starts, so no operations are
+/// wasted on coefficient arrays padded to a fixed length. For floating point numbers both `0.0`
+/// and `-0.0` are zero, and the result is the same as [`horner`](fn@horner), except when all
+/// the coefficients are zero and `x` is infinite or NaN: the skipped products `0 * x` would be NaN.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_trimmed};
+///
+/// let coeffs = [2, 3, 4, 0, 0, 0, 0, 0];
+///
+/// assert_eq!(horner_trimmed(7, &coeffs), 2 + 7 * (3 + 7 * 4));
+/// assert_eq!(horner_trimmed(7, &coeffs), horner(7, &coeffs));
+/// assert_eq!(horner_trimmed(7.0, &[0.0, 0.0, -0.0]), 0.0);
+/// ```
+#[track_caller]
+pub fn horner_trimmed<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let n = coeffs
+        .iter()
+        .rposition(|c| !c.is_zero())
+        .map_or(0, |k| k + 1);
+    horner(x, &coeffs[..n])
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at an affine transformation of the point.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `p(scale * x + shift)`, computing the argument once before the first step, without
+/// the caller having to build it; this is the usual form of polynomial approximations on an
+/// interval `[a, b]` mapped to `[-1, 1]`. The result is the same as
+/// [`horner`](fn@horner)`(scale * x + shift, coeffs)`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_affine;
+///
+/// // p(t) = 2 + 3t + 4t² at t = 2 * 3 + 1
+/// assert_eq!(horner_affine(3, 2, 1, &[2, 3, 4]), 2 + 7 * (3 + 7 * 4));
+/// ```
+#[track_caller]
+pub fn horner_affine<T>(x: T, scale: T, shift: T, coeffs: &[T]) -> T
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    horner(scale * &x + &shift, coeffs)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// using only operators on references.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner`](fn@horner), but `x` is taken by reference and every step `acc * x + c`
+/// borrows its operands, so neither `x` nor the coefficients are ever cloned or moved and the
+/// only values constructed are the intermediate results. `T` doesn't even need to implement
+/// [`Clone`]. This avoids copying large values, such as big integers, as long as the operators
+/// on references are implemented efficiently.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_by_ref;
+///
+/// assert_eq!(horner_by_ref(&7, &[2, 3, 4]), 2 + 7 * (3 + 7 * 4));
+/// ```
+#[track_caller]
+pub fn horner_by_ref<T>(x: &T, coeffs: &[T]) -> T
+where
+    T: Zero,
+    for<'a> &'a T: Mul<&'a T, Output = T> + Add<&'a T, Output = T>,
+{
+    let mut acc = T::zero();
+    for c in coeffs.iter().rev() {
+        acc = &(&acc * x) + c;
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// with coefficients of a different type than the point.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every coefficient is converted into `X` with [`Into`] right before being added to the
+/// accumulator, so there is no need to convert the whole coefficient array beforehand, e.g. to
+/// evaluate a polynomial with `i32` coefficients at a `f64` point, or one with real coefficients
+/// at a complex point. The conversion is lossless for the [`From`] implementations of the
+/// standard library.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_mixed;
+///
+/// assert_eq!(horner_mixed(0.5, &[2, 3, 4]), 2.0 + 0.5 * (3.0 + 0.5 * 4.0));
+/// assert_eq!(horner_mixed(7_i64, &[2_u8, 3, 4]), 2 + 7 * (3 + 7 * 4));
+/// ```
+#[track_caller]
+pub fn horner_mixed<X, C>(x: X, coeffs: &[C]) -> X
+where
+    X: Clone + Zero + Add<Output = X> + Mul<Output = X>,
+    C: Clone + Into<X>,
+{
+    let mut acc = X::zero();
+    for c in coeffs.iter().rev() {
+        acc = acc * x.clone() + c.clone().into();
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at many points.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Writes the value at `xs[i]` into `out[i]`, so a preallocated buffer can be reused and no
+/// allocation is required. This is the building block for specializations which evaluate
+/// several points at once, e.g. in the lanes of SIMD registers.
+///
+/// # Panics
+///
+/// Panics if `xs` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_many;
+///
+/// let mut out = [0; 3];
+/// horner_many(&[0, 1, 7], &[2, 3, 4], &mut out);
+///
+/// assert_eq!(out, [2, 9, 219]);
+/// ```
+#[track_caller]
+pub fn horner_many<T>(xs: &[T], coeffs: &[T], out: &mut [T])
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "the points and the output buffer must have the same length"
+    );
+    for (x, y) in xs.iter().zip(out) {
+        *y = horner(x.clone(), coeffs);
+    }
+}
+
+/// Evaluate many polynomials with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at the same point.
+///
+/// The coefficients of each polynomial are listed from zeroth order to highest.
+///
+/// Writes the value of `polys[i]` at `x` into `out[i]`, e.g. to apply a different filter to every
+/// channel of a signal. The polynomials may have different degrees. The results are the same as
+/// evaluating each polynomial separately with [`horner`](fn@horner); this is the entry point for
+/// specializations that share the powers of `x` across the polynomials.
+///
+/// # Panics
+///
+/// Panics if `polys` and `out` have different lengths.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_multi;
+///
+/// let mut out = [0; 3];
+/// horner_multi(7, &[&[2, 3, 4], &[1], &[0, 1]], &mut out);
+///
+/// assert_eq!(out, [219, 1, 7]);
+/// ```
+#[track_caller]
+pub fn horner_multi<T>(x: T, polys: &[&[T]], out: &mut [T])
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    assert_eq!(
+        polys.len(),
+        out.len(),
+        "the polynomials and the output buffer must have the same length"
+    );
+    for (coeffs, y) in polys.iter().zip(out) {
+        *y = horner(x.clone(), coeffs);
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using "fused multiply-add" instructions.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner`](fn@horner), but every step `acc * x + c` is computed with a single rounding
+/// by [`MulAdd::mul_add`]. The result is the same as the one computed by
+/// [`horner_fma!`](macro@horner_fma), with the coefficients known only at runtime.
+/// For floating point types, the `std` or `libm` feature is required: they select
+/// the fused multiply-add of the standard library or `libm::fma` respectively,
+/// so in `no_std` builds the operation is fused as well.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_fma;
+///
+/// let x = 7.0;
+///
+/// assert_eq!(horner_fma(x, &[]), 0.0);
+/// assert_eq!(horner_fma(x, &[2.0, 3.0, 4.0]), 2.0 + x * (3.0 + x * 4.0));
+///
+/// // the product is not rounded before the subtraction: (1 + ε)(1 - ε) - 1 = -ε²
+/// let e = 2.0_f64.powi(-30);
+/// assert_eq!(horner_fma(1.0 + e, &[-1.0, 1.0 - e]), -e * e);
+/// assert_eq!(-1.0 + (1.0 + e) * (1.0 - e), 0.0);
+/// ```
+#[track_caller]
+pub fn horner_fma<T: MulAdd<Output = T> + Zero + Copy>(x: T, coeffs: &[T]) -> T {
+    match coeffs.split_last() {
+        None => T::zero(),
+        Some((&last, rest)) => {
+            let mut acc = last;
+            for &c in rest.iter().rev() {
+                acc = acc.mul_add(x, c);
+            }
+            acc
+        }
+    }
 }
 
 /// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
@@ -163,6 +652,122 @@ macro_rules! horner_fma {
     }};
 }
 
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// checking its degree at compile time.
+///
+/// Same as [`horner!`], but the degree `N` of the polynomial must be specified at the end,
+/// and it is a compile time error if the number of coefficients is not `N + 1`.
+/// This guards against accidentally dropping or duplicating a coefficient while editing long
+/// lists of literals. The degree must be a constant expression of type `usize`.
+///
+/// # Usage
+///
+/// The macro can be invoked in any of the following ways:
+/// - `horner_poly!(x; a₀, a₁, ..., aₙ; degree = n)`,
+/// - `horner_poly!(x; a₀, a₁, ..., aₙ,; degree = n)`,
+/// - `horner_poly!(x; [a₀, a₁, ..., aₙ]; degree = n)`,
+/// - `horner_poly!(x; [a₀, a₁, ..., aₙ,]; degree = n)`,
+///
+/// and each of them may start with the keyword `let`, in which case the `x` argument is evaluated only once.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_poly;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_poly!(x; 2; degree = 0), 2);
+/// assert_eq!(
+///     horner_poly!(x; 2, 3, 4; degree = 2),
+///     2 + x * (3 + x * 4)
+/// );
+/// ```
+///
+/// A mismatched degree fails to compile, both with a dropped and with a duplicated coefficient:
+///
+/// ```compile_fail,E0080
+/// use polyeval::horner_poly;
+///
+/// let x = 7;
+///
+/// horner_poly!(x; 2, 3, 4; degree = 3);
+/// ```
+///
+/// ```compile_fail,E0080
+/// use polyeval::horner_poly;
+///
+/// let x = 7;
+///
+/// horner_poly!(x; 2, 3, 4, 4; degree = 2);
+/// ```
+#[macro_export]
+macro_rules! horner_poly {
+    ($x:expr; [$($coeffs:expr),+ $(,)?]; degree = $n:expr) => {
+        $crate::horner_poly!($x; $($coeffs),+; degree = $n)
+    };
+    (let $x:expr; $($t:tt)*) => {{
+        let x = $x;
+        $crate::horner_poly!(x; $($t)*)
+    }};
+    ($x:expr; $($coeffs:expr),+ $(,)?; degree = $n:expr) => {{
+        const _: () = ::core::assert!(
+            [$(::core::stringify!($coeffs)),+].len() == $n + 1,
+            "the number of coefficients must be the degree plus one"
+        );
+        $crate::horner!($x; $($coeffs),+)
+    }};
+}
+
+/// Build a closure which evaluates a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// At least one coefficient must be present;
+/// the coefficients may be enclosed in square brackets;
+/// a trailing comma is allowed at the end of the coefficients.
+///
+/// The macro expands to a closure `move |x| horner!(x; a₀, a₁, ..., aₙ)`, whose body is the
+/// fully unrolled Horner's scheme, so it can be stored and called at many points without
+/// allocating. The argument of the closure is evaluated only once, before the call, like for
+/// any function; the coefficients instead are evaluated anew at every call, hence they should
+/// be constants or cheap expressions.
+///
+/// The type of the argument is inferred from the calls of the closure;
+/// it may be necessary to annotate it when the coefficients are untyped literals.
+///
+/// # Usage
+///
+/// The macro can be invoked in any of the following ways:
+/// - `horner_closure!(a₀, a₁, ..., aₙ)`,
+/// - `horner_closure!(a₀, a₁, ..., aₙ,)`,
+/// - `horner_closure!([a₀, a₁, ..., aₙ])`,
+/// - `horner_closure!([a₀, a₁, ..., aₙ,])`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_closure;
+///
+/// let p = horner_closure!(2, 3, 4);
+///
+/// assert_eq!(p(7), 2 + 7 * (3 + 7 * 4));
+/// assert_eq!(p(-1), 3);
+///
+/// let q = horner_closure!([1.0_f64, -0.5, 0.25]);
+/// let values: Vec<f64> = [0.0, 2.0, 4.0].into_iter().map(q).collect();
+///
+/// assert_eq!(values, [1.0, 1.0, 3.0]);
+/// ```
+#[macro_export]
+macro_rules! horner_closure {
+    ([$($coeffs:expr),+ $(,)?]) => { $crate::horner_closure!($($coeffs),+) };
+    ($a:expr $(,)?) => { move |_| $a };
+    ($($coeffs:expr),+ $(,)?) => {
+        move |x| $crate::horner!(x; $($coeffs),+)
+    };
+}
+
 /// Evaluate a polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme).
 ///
 /// The coefficients are listed from zeroth order to highest.
@@ -332,6 +937,303 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_horner_val() {
+        use super::{horner, horner_val};
+        use crate::horner_deriv;
+        use core::ops::{Add, Mul};
+        use num_traits::Zero;
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Dual(f64, f64);
+
+        impl Add for Dual {
+            type Output = Dual;
+            fn add(self, rhs: Dual) -> Dual {
+                Dual(self.0 + rhs.0, self.1 + rhs.1)
+            }
+        }
+        impl Mul for Dual {
+            type Output = Dual;
+            fn mul(self, rhs: Dual) -> Dual {
+                Dual(self.0 * rhs.0, self.0 * rhs.1 + self.1 * rhs.0)
+            }
+        }
+        impl Zero for Dual {
+            fn zero() -> Dual {
+                Dual(0., 0.)
+            }
+            fn is_zero(&self) -> bool {
+                *self == Dual(0., 0.)
+            }
+        }
+
+        let coeffs = [1., -2., 3., -4., 5., -6.];
+        let duals = coeffs.map(|c| Dual(c, 0.));
+        for x in -16..16 {
+            assert_eq!(horner_val(x, &[1, 2, 3]), horner(x, &[1, 2, 3]));
+            let x = x as f64 / 4.;
+            assert_eq!(horner_val(Dual(x, 1.), &[]), Dual(0., 0.));
+            let Dual(value, derivative) = horner_val(Dual(x, 1.), &duals);
+            let (p, d) = horner_deriv(x, &coeffs);
+            assert_eq!(value, p);
+            assert!((derivative - d).abs() <= 1e-12 * d.abs().max(1.));
+        }
+    }
+
+    #[test]
+    fn test_horner_trimmed() {
+        use super::{horner, horner_trimmed};
+        use core::ops::{Add, Mul};
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use num_traits::Zero;
+
+        static MULTIPLICATIONS: AtomicUsize = AtomicUsize::new(0);
+
+        /// Counts the multiplications.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Counted(i64);
+
+        impl Add for Counted {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Counted(self.0 + rhs.0)
+            }
+        }
+        impl Add<&Self> for Counted {
+            type Output = Self;
+            fn add(self, rhs: &Self) -> Self {
+                Counted(self.0 + rhs.0)
+            }
+        }
+        impl Mul<&Self> for Counted {
+            type Output = Self;
+            fn mul(self, rhs: &Self) -> Self {
+                MULTIPLICATIONS.fetch_add(1, Ordering::Relaxed);
+                Counted(self.0 * rhs.0)
+            }
+        }
+        impl Zero for Counted {
+            fn zero() -> Self {
+                Counted(0)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        let mut coeffs = [0; 16];
+        coeffs[..5].copy_from_slice(&[1, -2, 3, -4, 5]);
+        for x in -8..8 {
+            assert_eq!(horner_trimmed(x, &coeffs), horner(x, &coeffs));
+            assert_eq!(horner_trimmed(x, &[0; 4]), 0);
+            assert_eq!(horner_trimmed(x, &[]), 0);
+            let x = x as f64 / 4.;
+            let floats = coeffs.map(|c| c as f64);
+            assert_eq!(horner_trimmed(x, &floats), horner(x, &floats));
+            let signed = [1., 0., -0., 0., -0.];
+            assert_eq!(horner_trimmed(x, &signed), horner(x, &signed));
+        }
+        // only the significant coefficients are processed
+        let padded = coeffs.map(Counted);
+        let y = horner(Counted(2), &padded);
+        assert_eq!(MULTIPLICATIONS.swap(0, Ordering::Relaxed), 16);
+        assert_eq!(horner_trimmed(Counted(2), &padded), y);
+        assert_eq!(MULTIPLICATIONS.swap(0, Ordering::Relaxed), 5);
+        assert_eq!(horner_trimmed(Counted(2), &[Counted(0); 8]), Counted(0));
+        assert_eq!(MULTIPLICATIONS.load(Ordering::Relaxed), 0);
+        // non-finite points propagate differently
+        assert!(horner_trimmed(f64::INFINITY, &[1., 0.]).is_nan());
+        assert!(horner(f64::INFINITY, &[0., -0.]).is_nan());
+        assert_eq!(horner_trimmed(f64::INFINITY, &[0., -0.]), 0.);
+    }
+
+    #[test]
+    fn test_horner_affine() {
+        use super::{horner, horner_affine};
+        let coeffs = [1, -2, 3, -4, 5];
+        for x in -8..8 {
+            for (scale, shift) in [(1, 0), (2, 1), (-3, 5), (0, 7)] {
+                assert_eq!(
+                    horner_affine(x, scale, shift, &coeffs),
+                    horner(scale * x + shift, &coeffs)
+                );
+            }
+            assert_eq!(horner_affine(x, 2, 1, &[]), 0);
+        }
+        // [0, 8] mapped to [-1, 1]
+        let coeffs = [0.5, -1., 0.25, 2.];
+        for x in 0..=32 {
+            let x = x as f64 / 4.;
+            let (scale, shift) = (0.25, -1.);
+            assert_eq!(
+                horner_affine(x, scale, shift, &coeffs),
+                horner(scale * x + shift, &coeffs)
+            );
+        }
+    }
+
+    #[test]
+    fn test_horner_by_ref() {
+        use super::{horner, horner_by_ref};
+        use core::ops::{Add, Mul};
+        use num_traits::Zero;
+
+        // not `Clone`, so it can't be copied behind the scenes
+        #[derive(Debug, PartialEq)]
+        struct Opaque(i64);
+
+        impl Add for Opaque {
+            type Output = Opaque;
+            fn add(self, rhs: Opaque) -> Opaque {
+                Opaque(self.0 + rhs.0)
+            }
+        }
+        impl<'a> Add<&'a Opaque> for &'a Opaque {
+            type Output = Opaque;
+            fn add(self, rhs: &Opaque) -> Opaque {
+                Opaque(self.0 + rhs.0)
+            }
+        }
+        impl<'a> Mul<&'a Opaque> for &'a Opaque {
+            type Output = Opaque;
+            fn mul(self, rhs: &Opaque) -> Opaque {
+                Opaque(self.0 * rhs.0)
+            }
+        }
+        impl Zero for Opaque {
+            fn zero() -> Opaque {
+                Opaque(0)
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        let coeffs = [1, -2, 3, -4, 5];
+        let opaque = coeffs.map(Opaque);
+        for x in -16..16 {
+            assert_eq!(horner_by_ref(&x, &coeffs), horner(x, &coeffs));
+            assert_eq!(
+                horner_by_ref(&Opaque(x), &opaque),
+                Opaque(horner(x, &coeffs))
+            );
+            assert_eq!(horner_by_ref(&Opaque(x), &[]), Opaque(0));
+        }
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn test_horner_by_ref_bigint() {
+        use super::{horner, horner_by_ref};
+        use num_bigint::BigInt;
+        let coeffs: [BigInt; 8] = core::array::from_fn(|k| BigInt::from(k as i64 - 3).pow(40));
+        for x in -8..8 {
+            let x = BigInt::from(x).pow(30);
+            assert_eq!(horner_by_ref(&x, &coeffs), horner(x.clone(), &coeffs));
+        }
+        // 1 + 10²⁰ x with x = 10²⁰
+        let ten = BigInt::from(10).pow(20);
+        let expected = BigInt::from(10).pow(40) + 1;
+        assert_eq!(
+            horner_by_ref(&ten, &[BigInt::from(1), ten.clone()]),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_horner_mixed() {
+        use super::{horner, horner_mixed};
+        let coeffs = [1, -2, 3, -4, 5];
+        let floats = coeffs.map(f64::from);
+        for x in -16..16 {
+            assert_eq!(
+                horner_mixed(x as i64, &coeffs),
+                horner(x as i64, &[1, -2, 3, -4, 5])
+            );
+            let x = x as f64 / 4.;
+            assert_eq!(horner_mixed(x, &[] as &[i32]), 0.);
+            assert_eq!(horner_mixed(x, &coeffs), horner(x, &floats));
+            assert_eq!(
+                horner_mixed(x, &floats.map(|c| c as f32)),
+                horner(x, &floats)
+            );
+        }
+    }
+
+    #[cfg(feature = "num-complex")]
+    #[test]
+    fn test_horner_mixed_complex() {
+        use super::{horner, horner_mixed};
+        use num_complex::Complex;
+        let coeffs = [1., -2., 3., -4., 5.];
+        let complex = coeffs.map(Complex::from);
+        for re in -8..8 {
+            for im in -8..8 {
+                let z = Complex::new(re as f64 / 4., im as f64 / 4.);
+                assert_eq!(horner_mixed(z, &coeffs), horner(z, &complex));
+            }
+        }
+        // p(i) = 1 - 2i - 3 + 4i + 5
+        assert_eq!(horner_mixed(Complex::i(), &coeffs), Complex::new(3., 2.));
+    }
+
+    #[test]
+    fn test_horner_multi() {
+        use super::{horner, horner_multi};
+        let polys: [&[f64]; 5] = [
+            &[],
+            &[3.],
+            &[1., -2.],
+            &[0.5, 0., 0.25, -1.],
+            &[1., 1., 1., 1., 1., 1.],
+        ];
+        let mut out = [0.; 5];
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            horner_multi(x, &polys, &mut out);
+            for (coeffs, &y) in polys.iter().zip(&out) {
+                assert_eq!(y, horner(x, coeffs));
+            }
+        }
+        horner_multi(2, &[], &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "the polynomials and the output buffer must have the same length")]
+    fn test_horner_multi_wrong_length() {
+        use super::horner_multi;
+        horner_multi(2, &[&[1, 2], &[3]], &mut [0; 3]);
+    }
+
+    #[test]
+    fn test_horner_many() {
+        use super::{horner, horner_many};
+        let xs: [i32; 32] = core::array::from_fn(|k| k as i32 - 16);
+        let mut out = [0; 32];
+        horner_many(&xs, &[], &mut out);
+        assert_eq!(out, [0; 32]);
+        let coeffs = [1, -2, 3, -4, 5];
+        horner_many(&xs, &coeffs, &mut out);
+        for (&x, &y) in xs.iter().zip(&out) {
+            assert_eq!(y, horner(x, &coeffs));
+        }
+        horner_many(&[], &coeffs, &mut []);
+        let xs = xs.map(|x| x as f64 / 4.);
+        let mut out = [f64::NAN; 32];
+        horner_many(&xs, &[0.5, 1., -0.25], &mut out);
+        for (&x, &y) in xs.iter().zip(&out) {
+            assert_eq!(y, horner(x, &[0.5, 1., -0.25]));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "must have the same length")]
+    fn test_horner_many_length_mismatch() {
+        use super::horner_many;
+        horner_many(&[1, 2, 3], &[1, 1], &mut [0; 2]);
+    }
+
     #[test]
     fn test_macro_horner() {
         for x in 0..32 {
@@ -357,7 +1259,61 @@ mod tests {
         }
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[test]
+    fn test_macro_horner_closure() {
+        let constant = horner_closure!(1);
+        let line = horner_closure!(1, 2,);
+        let quartic = horner_closure!([1, 2, 3, 4, 5]);
+        for x in 0..32 {
+            assert_eq!(constant(x), 1);
+            assert_eq!(line(x), 1 + 2 * x);
+            assert_eq!(quartic(x), 1 + x * (2 + x * (3 + x * (4 + x * 5))));
+        }
+        let quartic = horner_closure!(1., 2., 3., 4., 5.);
+        for x in 0..32 {
+            let x = x as f32;
+            assert_eq!(quartic(x), horner!(x; 1., 2., 3., 4., 5.));
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_horner_fma() {
+        use super::{horner, horner_fma};
+        for x in 0..32 {
+            assert_eq!(horner_fma(x, &[]), 0);
+            assert_eq!(horner_fma(x, &[1, 2, 3, 4, 5]), horner(x, &[1, 2, 3, 4, 5]));
+            let x = x as f64 / 8.;
+            assert_eq!(horner_fma(x, &[]), 0.);
+            assert_eq!(horner_fma(x, &[1.]), 1.);
+            assert_eq!(horner_fma(x, &[1., 2.]), 1. + 2. * x);
+            assert_eq!(
+                horner_fma(x, &[1. / 3., 2. / 7., 3. / 11., 4. / 13., 5. / 17.]),
+                horner_fma!(x; 1. / 3., 2. / 7., 3. / 11., 4. / 13., 5. / 17.)
+            );
+            let y = x as f32;
+            assert_eq!(
+                horner_fma(y, &[1. / 3., 2. / 7., 3. / 11.]),
+                horner_fma!(y; 1. / 3., 2. / 7., 3. / 11.)
+            );
+        }
+        // catastrophic cancellation: (1 + ε)(1 - ε) - 1 = -ε²,
+        // which the rounded product loses entirely
+        let e = 2f64.powi(-30);
+        let (x, coeffs) = (1. + e, [-1., 1. - e]);
+        assert_eq!(horner(x, &coeffs), 0.);
+        assert_eq!(horner_fma(x, &coeffs), -e * e);
+        let e = 2f32.powi(-13);
+        let (x, coeffs) = (1. + e, [-1., 1. - e]);
+        assert_eq!(horner(x, &coeffs), 0.);
+        assert_eq!(horner_fma(x, &coeffs), -e * e);
+        // (x - 1)² = x² - 2x + 1 close to its double root
+        let x = 1. + 2f64.powi(-27);
+        assert_eq!(horner(x, &[1., -2., 1.]), 0.);
+        assert_eq!(horner_fma(x, &[1., -2., 1.]), 2f64.powi(-54));
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     fn test_macro_horner_fma() {
         for x in 0..32 {
@@ -408,7 +1364,7 @@ mod tests {
         }
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     fn test_macro_estrin_fma() {
         for x in 0..32 {
@@ -461,7 +1417,7 @@ mod tests {
         assert_eq!(horner!(let f(); [1, 2, 3]), 6);
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     #[should_panic(expected = "function called more than once")]
     fn test_macro_horner_fma_cannot_call_more_than_once() {
@@ -469,7 +1425,7 @@ mod tests {
         assert_eq!(horner_fma!(f(); 1, 2, 3), 6);
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     fn test_macro_horner_fma_can_call_more_than_once() {
         let mut f = make_callable_only_once();
@@ -493,7 +1449,7 @@ mod tests {
         assert_eq!(estrin!(let f(); [1, 2, 3]), 6);
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     #[should_panic(expected = "function called more than once")]
     fn test_macro_estrin_fma_cannot_call_more_than_once() {
@@ -501,7 +1457,7 @@ mod tests {
         assert_eq!(estrin_fma!(f(); 1, 2, 3), 6);
     }
 
-    #[cfg(any(feature = "std", feature = "lib"))]
+    #[cfg(any(feature = "std", feature = "libm"))]
     #[test]
     fn test_macro_estrin_fma_can_call_more_than_once() {
         let mut f = make_callable_only_once();
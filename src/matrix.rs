@@ -0,0 +1,157 @@
+//! Evaluation at square matrices.
+
+use num_traits::{One, Zero};
+
+/// Minimal interface to the arithmetic of square matrices.
+///
+/// This is implemented for arrays `[[T; N]; N]` of rows, and is meant to be implemented,
+/// possibly through a newtype, for external matrix types.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::SquareMatrix;
+///
+/// /// Diagonal matrices, stored as the vector of the diagonal.
+/// #[derive(Clone, Debug, PartialEq)]
+/// struct Diagonal(Vec<f64>);
+///
+/// impl SquareMatrix for Diagonal {
+///     type Scalar = f64;
+///     fn identity(&self) -> Self {
+///         Diagonal(vec![1.0; self.0.len()])
+///     }
+///     fn add(&self, other: &Self) -> Self {
+///         Diagonal(self.0.iter().zip(&other.0).map(|(a, b)| a + b).collect())
+///     }
+///     fn mul(&self, other: &Self) -> Self {
+///         Diagonal(self.0.iter().zip(&other.0).map(|(a, b)| a * b).collect())
+///     }
+///     fn scale(&self, c: &f64) -> Self {
+///         Diagonal(self.0.iter().map(|a| c * a).collect())
+///     }
+/// }
+/// ```
+pub trait SquareMatrix: Sized {
+    /// The type of the entries.
+    type Scalar;
+
+    /// Returns the identity matrix of the same size as `self`.
+    fn identity(&self) -> Self;
+
+    /// Returns the sum `self + other`.
+    fn add(&self, other: &Self) -> Self;
+
+    /// Returns the matrix product `self * other`.
+    fn mul(&self, other: &Self) -> Self;
+
+    /// Returns the product `c * self`.
+    fn scale(&self, c: &Self::Scalar) -> Self;
+}
+
+impl<T: Copy + Zero + One, const N: usize> SquareMatrix for [[T; N]; N] {
+    type Scalar = T;
+
+    fn identity(&self) -> Self {
+        core::array::from_fn(|i| {
+            core::array::from_fn(|j| if i == j { T::one() } else { T::zero() })
+        })
+    }
+
+    fn add(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| core::array::from_fn(|j| self[i][j] + other[i][j]))
+    }
+
+    fn mul(&self, other: &Self) -> Self {
+        core::array::from_fn(|i| {
+            core::array::from_fn(|j| {
+                (0..N).fold(T::zero(), |acc, k| acc + self[i][k] * other[k][j])
+            })
+        })
+    }
+
+    fn scale(&self, c: &T) -> Self {
+        core::array::from_fn(|i| core::array::from_fn(|j| *c * self[i][j]))
+    }
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at a square matrix.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Computes `p(A) = c₀ I + c₁ A + ... + cₙ Aⁿ`, where the scalar coefficients multiply the
+/// identity, with the steps `acc = acc * A + cₖ I`. The accumulator is initialized with
+/// `cₙ I`, so only `n` matrix products are performed for a polynomial of degree `n`.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_matrix;
+///
+/// let a = [[1.0, 2.0], [3.0, 4.0]];
+///
+/// // 1 + 2A + A²
+/// assert_eq!(horner_matrix(&a, &[1.0, 2.0, 1.0]), [[10.0, 14.0], [21.0, 31.0]]);
+/// // A satisfies its characteristic polynomial x² - 5x - 2 (Cayley–Hamilton)
+/// assert_eq!(horner_matrix(&a, &[-2.0, -5.0, 1.0]), [[0.0; 2]; 2]);
+/// ```
+pub fn horner_matrix<M>(a: &M, coeffs: &[M::Scalar]) -> M
+where
+    M: SquareMatrix,
+    M::Scalar: Zero,
+{
+    let identity = a.identity();
+    let Some((last, rest)) = coeffs.split_last() else {
+        return identity.scale(&M::Scalar::zero());
+    };
+    let mut acc = identity.scale(last);
+    for c in rest.iter().rev() {
+        acc = acc.mul(a).add(&identity.scale(c));
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_square_matrix() {
+        use super::SquareMatrix;
+        let a = [[1, 2], [3, 4]];
+        let b = [[0, 1], [-1, 2]];
+        assert_eq!(a.identity(), [[1, 0], [0, 1]]);
+        assert_eq!(a.add(&b), [[1, 3], [2, 6]]);
+        assert_eq!(a.mul(&b), [[-2, 5], [-4, 11]]);
+        assert_eq!(b.mul(&a), [[3, 4], [5, 6]]);
+        assert_eq!(a.scale(&3), [[3, 6], [9, 12]]);
+        assert_eq!([[5]].mul(&[[7]]), [[35]]);
+    }
+
+    #[test]
+    fn test_horner_matrix() {
+        use super::horner_matrix;
+        use crate::horner;
+        let a = [[1, 2], [3, 4]];
+        assert_eq!(horner_matrix(&a, &[]), [[0, 0], [0, 0]]);
+        assert_eq!(horner_matrix(&a, &[5]), [[5, 0], [0, 5]]);
+        assert_eq!(horner_matrix(&a, &[0, 1]), a);
+        // A² = [[7, 10], [15, 22]], A³ = [[37, 54], [81, 118]]
+        assert_eq!(horner_matrix(&a, &[0, 0, 1]), [[7, 10], [15, 22]]);
+        assert_eq!(horner_matrix(&a, &[1, 2, 3, 4]), [[172, 250], [375, 547]]);
+        // Cayley–Hamilton: the characteristic polynomial x² - 5x - 2 vanishes at A
+        assert_eq!(horner_matrix(&a, &[-2, -5, 1]), [[0, 0], [0, 0]]);
+        // nilpotent: N² = 0, so p(N) = c₀ I + c₁ N
+        let n = [[0, 1], [0, 0]];
+        assert_eq!(horner_matrix(&n, &[2, 3, 4, 5]), [[2, 3], [0, 2]]);
+        // rotation by a quarter turn: R² = -I, so p(R) = (1 - 3) I + (2 - 4) R
+        let r = [[0, -1], [1, 0]];
+        assert_eq!(horner_matrix(&r, &[1, 2, 3, 4]), [[-2, 2], [-2, -2]]);
+        // on diagonal matrices the result is the diagonal of the values
+        let d = [[2., 0.], [0., -0.5]];
+        let coeffs = [1., -2., 3., -4., 5.];
+        assert_eq!(
+            horner_matrix(&d, &coeffs),
+            [[horner(2., &coeffs), 0.], [0., horner(-0.5, &coeffs)]]
+        );
+    }
+}
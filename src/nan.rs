@@ -0,0 +1,206 @@
+//! Evaluation with explicit handling of NaN.
+
+use core::fmt;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// preserving the payload of NaN inputs.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// If any input is NaN, returns the first NaN encountered, bit-for-bit: `x` is checked first,
+/// then the coefficients in the order in which they are listed.
+/// Otherwise returns the same value as [`horner`](fn@crate::horner); in this case a NaN in the
+/// result (e.g. from `∞ - ∞`) doesn't carry any meaningful payload.
+///
+/// The inputs are checked explicitly before doing any arithmetic, because IEEE 754 doesn't
+/// specify which payload the result of an operation with NaN operands carries, and Rust
+/// doesn't guarantee that it is preserved at all (see [`f64::NAN`] and the documentation
+/// of the primitive type `f64`). This makes it possible to encode provenance in the payloads.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_nan_payload;
+///
+/// let tagged = f64::from_bits(f64::NAN.to_bits() | 42);
+///
+/// assert_eq!(horner_nan_payload(7.0, &[2.0, 3.0, 4.0]), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// assert_eq!(horner_nan_payload(7.0, &[2.0, tagged, 4.0]).to_bits(), tagged.to_bits());
+/// ```
+pub fn horner_nan_payload(x: f64, coeffs: &[f64]) -> f64 {
+    if x.is_nan() {
+        return x;
+    }
+    if let Some(&nan) = coeffs.iter().find(|c| c.is_nan()) {
+        return nan;
+    }
+    coeffs.iter().rfold(0.0, |acc, &c| acc * x + c)
+}
+
+/// Error returned by [`horner_checked_finite`] when a value is infinite or NaN.
+///
+/// The steps of Horner's method are numbered by the degree of the coefficient which they add,
+/// `acc = acc * x + coeffs[step]`, so they run from `coeffs.len() - 1` down to `0`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonFiniteError {
+    /// Step at which the accumulator first became infinite or NaN.
+    pub step: usize,
+    /// Value of the accumulator after that step.
+    pub value: f64,
+}
+
+impl fmt::Display for NonFiniteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "non-finite value {} when adding the coefficient of degree {}",
+            self.value, self.step
+        )
+    }
+}
+
+impl core::error::Error for NonFiniteError {}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// checking that all the intermediate values are finite.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the same value as [`horner`](fn@crate::horner) if the accumulator stays finite
+/// throughout the evaluation, otherwise a [`NonFiniteError`] reporting the first step at which
+/// it became infinite (e.g. by overflow) or NaN (e.g. from a NaN input or `∞ - ∞`).
+/// The evaluation stops there, so an overflow is reported as such even if it would turn into NaN
+/// later, e.g. through `∞ - ∞`. As in [`horner`](fn@crate::horner), the accumulator starts from zero,
+/// so an infinite or NaN `x` is reported at the first step, unless there are no coefficients.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_checked_finite, NonFiniteError};
+///
+/// assert_eq!(horner_checked_finite(7.0, &[2.0, 3.0, 4.0]), Ok(219.0));
+///
+/// // 1e200 * 1e200 overflows when adding the coefficient of degree 0
+/// let err = horner_checked_finite(1e200, &[1.0, 1e200]).unwrap_err();
+/// assert_eq!(err, NonFiniteError { step: 0, value: f64::INFINITY });
+/// ```
+pub fn horner_checked_finite(x: f64, coeffs: &[f64]) -> Result<f64, NonFiniteError> {
+    let mut acc = 0.0;
+    for (step, &c) in coeffs.iter().enumerate().rev() {
+        acc = acc * x + c;
+        if !acc.is_finite() {
+            return Err(NonFiniteError { step, value: acc });
+        }
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    fn tagged(payload: u64) -> f64 {
+        f64::from_bits(f64::NAN.to_bits() | payload)
+    }
+
+    #[test]
+    fn test_horner_nan_payload() {
+        use super::horner_nan_payload;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            assert_eq!(horner_nan_payload(x, &[]), 0.);
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_nan_payload(x, &coeffs), horner(x, &coeffs));
+            for k in 0..coeffs.len() {
+                let mut coeffs = coeffs;
+                coeffs[k] = tagged(k as u64 + 1);
+                let y = horner_nan_payload(x, &coeffs);
+                assert_eq!(y.to_bits(), tagged(k as u64 + 1).to_bits());
+                // the first one wins
+                coeffs[k..].fill(tagged(1000));
+                coeffs[k] = tagged(k as u64 + 1);
+                let y = horner_nan_payload(x, &coeffs);
+                assert_eq!(y.to_bits(), tagged(k as u64 + 1).to_bits());
+            }
+        }
+        // negative NaN with a payload
+        let nan = -tagged(0xdead);
+        assert_eq!(horner_nan_payload(2., &[1., nan]).to_bits(), nan.to_bits());
+        // the point is checked first
+        let y = horner_nan_payload(tagged(7), &[tagged(8)]);
+        assert_eq!(y.to_bits(), tagged(7).to_bits());
+        assert!(horner_nan_payload(f64::INFINITY, &[f64::INFINITY, -1.]).is_nan());
+    }
+
+    #[test]
+    fn test_horner_checked_finite() {
+        use super::{horner_checked_finite, NonFiniteError};
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5.];
+        for x in -32..32 {
+            let x = x as f64 / 4.;
+            assert_eq!(horner_checked_finite(x, &coeffs), Ok(horner(x, &coeffs)));
+        }
+        assert_eq!(horner_checked_finite(2., &[]), Ok(0.));
+        // overflow to infinity: 2¹⁰⁰⁰ is finite, but 2²⁰⁰⁰ is not
+        let big = 2f64.powi(1000);
+        let err = horner_checked_finite(big, &[0., 0., 1.]).unwrap_err();
+        assert_eq!(
+            err,
+            NonFiniteError {
+                step: 0,
+                value: f64::INFINITY
+            }
+        );
+        let err = horner_checked_finite(-big, &[1., 0., 0., 1.]).unwrap_err();
+        assert_eq!(
+            err,
+            NonFiniteError {
+                step: 1,
+                value: f64::INFINITY
+            }
+        );
+        assert_eq!(horner_checked_finite(big, &[0., 1.]), Ok(big));
+        // the overflow is reported, even if it turns into NaN later
+        let coeffs = [f64::NEG_INFINITY, 0., 0., 1.];
+        let err = horner_checked_finite(big, &coeffs).unwrap_err();
+        assert_eq!(err.step, 1);
+        assert_eq!(err.value, f64::INFINITY);
+        assert!(horner(big, &coeffs).is_nan());
+        let err = horner_checked_finite(2., &[1., f64::MAX, f64::MAX]).unwrap_err();
+        assert_eq!(err.step, 1);
+        assert_eq!(err.value, f64::INFINITY);
+        // NaN inputs
+        let err = horner_checked_finite(f64::NAN, &[1., 2.]).unwrap_err();
+        assert_eq!(err.step, 1);
+        assert!(err.value.is_nan());
+        let err = horner_checked_finite(2., &[1., f64::NAN, 3.]).unwrap_err();
+        assert_eq!(err.step, 1);
+        assert!(err.value.is_nan());
+        let err = horner_checked_finite(f64::INFINITY, &[1.]).unwrap_err();
+        assert_eq!(err.step, 0);
+        assert!(err.value.is_nan());
+        let err = horner_checked_finite(2., &[f64::NEG_INFINITY]).unwrap_err();
+        assert_eq!(
+            err,
+            NonFiniteError {
+                step: 0,
+                value: f64::NEG_INFINITY
+            }
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_non_finite_error_display() {
+        use super::NonFiniteError;
+        use alloc::string::ToString;
+        let err = NonFiniteError {
+            step: 3,
+            value: f64::INFINITY,
+        };
+        assert_eq!(
+            err.to_string(),
+            "non-finite value inf when adding the coefficient of degree 3"
+        );
+    }
+}
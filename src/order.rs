@@ -0,0 +1,278 @@
+//! Handling of the order of the coefficients.
+
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients are listed from highest order to zeroth, i.e. the first one is the leading
+/// coefficient, as in many references. The result is the same as [`horner`](fn@crate::horner)
+/// with the coefficients reversed, without the need to reverse them.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_desc};
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_desc(x, &[]), 0);
+/// assert_eq!(horner_desc(x, &[4, 3, 2]), 2 + x * (3 + x * 4));
+/// assert_eq!(horner_desc(x, &[4, 3, 2]), horner(x, &[2, 3, 4]));
+/// ```
+#[track_caller]
+pub fn horner_desc<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let mut acc = T::zero();
+    for c in coeffs {
+        acc = acc * &x + c;
+    }
+    acc
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// switching to the reciprocal point for large `|x|`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// For `|x| <= 1`, and for `|x| > 1` whenever it is finite, the result is the same as
+/// [`horner`](fn@crate::horner). Otherwise the trailing zero coefficients are dropped, and the
+/// polynomial of degree `n` is rewritten as `p(x) = xⁿ q(1/x)`, where `q` has the coefficients reversed:
+/// `q` is evaluated with [`horner_desc`] at `1/x`, where the powers decrease instead of growing,
+/// and then multiplied by `x` one factor at a time, so the intermediate values increase
+/// monotonically towards the result. The intermediate values of `q(1/x)` are bounded by the sum
+/// `∑ |cₖ|` (up to rounding), so if this sum doesn't overflow, the result is finite whenever the
+/// value of the polynomial is, even if plain Horner overflows because of a cancellation between
+/// large terms. Otherwise the evaluation of `q` may overflow too, e.g. for the coefficients
+/// `[MAX, MAX, -MAX]` at `x = 1.5`, whose value is about `0.25 MAX`.
+/// The fallback costs a second evaluation, `n` additional multiplications and a division.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_reciprocal};
+///
+/// // x MAX - MAX at x = 1.5
+/// let coeffs = [-f64::MAX, f64::MAX];
+///
+/// assert_eq!(horner(1.5, &coeffs), f64::INFINITY);
+/// assert!((horner_reciprocal(1.5, &coeffs) - 0.5 * f64::MAX).abs() < 1e-15 * f64::MAX);
+///
+/// assert_eq!(horner_reciprocal(0.5, &[2.0, 3.0, 4.0]), horner(0.5, &[2.0, 3.0, 4.0]));
+/// ```
+pub fn horner_reciprocal(x: f64, coeffs: &[f64]) -> f64 {
+    let y = coeffs.iter().rfold(0.0, |acc, &c| acc * x + c);
+    if x.abs() <= 1.0 || y.is_finite() {
+        return y;
+    }
+    // the nominal degree would make q(1/x) underflow needlessly
+    let coeffs = trim_zeros(coeffs);
+    let mut acc = horner_desc(1.0 / x, coeffs);
+    for _ in 1..coeffs.len() {
+        acc *= x;
+    }
+    acc
+}
+
+/// Returns the coefficients without the trailing zeros, i.e. up to the true degree.
+pub(crate) fn trim_zeros(coeffs: &[f64]) -> &[f64] {
+    let len = coeffs.iter().rposition(|&c| c != 0.0).map_or(0, |k| k + 1);
+    &coeffs[..len]
+}
+
+/// Returns `true` if the coefficients look like they are listed from highest order to lowest.
+///
+/// This is the case when their magnitudes are non-decreasing over at least three orders
+/// of magnitude, as opposed to the decay typical of series expansions, and evaluating them at `x`
+/// in the two orders gives wildly different results.
+fn looks_reversed(x: f64, coeffs: &[f64]) -> bool {
+    const RATIO: f64 = 1e3;
+    let (first, last) = match coeffs {
+        [first, .., last] if coeffs.len() >= 3 => (first.abs(), last.abs()),
+        _ => return false,
+    };
+    if first == 0.0 || last < RATIO * first {
+        return false;
+    }
+    if coeffs.windows(2).any(|w| w[0].abs() > w[1].abs()) {
+        return false;
+    }
+    let forward = coeffs.iter().rfold(0.0, |acc, &c| acc * x + c);
+    let reversed = coeffs.iter().fold(0.0, |acc, &c| acc * x + c);
+    forward.abs() > RATIO * reversed.abs()
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// checking in debug builds that the coefficients are not accidentally reversed.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// In debug builds this panics (via [`debug_assert!`]) when the coefficients look like they are
+/// listed from highest order to lowest: their magnitudes are non-decreasing over at least
+/// three orders of magnitude, unlike the decaying coefficients of series expansions, and the two
+/// orders give values at `x` which differ by more than three orders of magnitude.
+/// This is a heuristic lint meant to catch a common mistake, so it may have both false positives
+/// and false negatives.
+///
+/// In release builds the check is skipped and this is the same as [`horner`](fn@crate::horner).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_checked_order;
+///
+/// // truncated series of exp(x)
+/// let coeffs = [1.0, 1.0, 1.0 / 2.0, 1.0 / 6.0, 1.0 / 24.0];
+///
+/// assert_eq!(
+///     horner_checked_order(2.0, &coeffs),
+///     1.0 + 2.0 * (1.0 + 2.0 * (0.5 + 2.0 * (1.0 / 6.0 + 2.0 * (1.0 / 24.0))))
+/// );
+/// ```
+///
+/// ```should_panic
+/// # // the check only happens in debug builds
+/// # if !cfg!(debug_assertions) { panic!() }
+/// use polyeval::horner_checked_order;
+///
+/// // oops, x⁴ + 10x³ + 100x² + 1000x + 10000 from highest order to lowest
+/// let coeffs = [1.0, 10.0, 100.0, 1000.0, 10000.0];
+///
+/// horner_checked_order(100.0, &coeffs);
+/// ```
+pub fn horner_checked_order(x: f64, coeffs: &[f64]) -> f64 {
+    debug_assert!(
+        !looks_reversed(x, coeffs),
+        "the coefficients look like they are listed from highest order to lowest, \
+         but they must be listed from zeroth order to highest"
+    );
+    coeffs.iter().rfold(0.0, |acc, &c| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_reciprocal() {
+        use super::horner_reciprocal;
+        use crate::horner;
+        let coeffs = [1., -2., 3., -4., 5., -6., 7.];
+        for x in -64..=64 {
+            let x = x as f64 / 8.;
+            assert_eq!(horner_reciprocal(x, &[]), 0.);
+            assert_eq!(horner_reciprocal(x, &[3.]), 3.);
+            let expected = horner(x, &coeffs);
+            let y = horner_reciprocal(x, &coeffs);
+            if x.abs() <= 1. {
+                assert_eq!(y, expected);
+            } else {
+                assert!(
+                    (y - expected).abs() <= 1e-14 * expected.abs(),
+                    "{y} != {expected}"
+                );
+            }
+        }
+        // large arguments, where plain Horner doesn't overflow either
+        let x = 1e40;
+        let y = horner_reciprocal(x, &coeffs);
+        assert!((y - horner(x, &coeffs)).abs() <= 1e-15 * y.abs());
+        assert_eq!(horner_reciprocal(1e200, &[0., 0., 1e-300]), 1e100);
+        // cancellations between large terms overflow in plain Horner
+        let max = f64::MAX;
+        for (coeffs, expected) in [
+            (&[-max, max][..], 0.5 * max),
+            (&[max, -max][..], -0.5 * max),
+            (&[-max, 0., 0.5 * max][..], 0.125 * max),
+        ] {
+            assert!(horner(1.5, coeffs).is_infinite());
+            let y = horner_reciprocal(1.5, coeffs);
+            assert!((y - expected).abs() <= 1e-15 * max, "{y} != {expected}");
+        }
+        // the partial sum MAX + 2/3 MAX of q(1/x) overflows, though the value is about MAX / 4
+        assert!(horner(1.5, &[max, max, -max]).is_infinite());
+        assert_eq!(horner_reciprocal(1.5, &[max, max, -max]), f64::INFINITY);
+        // genuine overflows are still infinite
+        assert_eq!(horner_reciprocal(2., &[0., max]), f64::INFINITY);
+        assert_eq!(horner_reciprocal(-1e300, &[1., 0., 1.]), f64::INFINITY);
+        // trailing zeros don't count towards the degree
+        let mut padded = [0.; 21];
+        padded[..2].copy_from_slice(&[1., 1.]);
+        assert_eq!(horner_reciprocal(1e20, &padded), horner(1e20, &padded));
+        assert_eq!(horner_reciprocal(1e20, &padded), 1e20);
+        padded[..2].copy_from_slice(&[-max, max]);
+        let y = horner_reciprocal(1.5, &padded);
+        assert!((y - 0.5 * max).abs() <= 1e-15 * max, "{y}");
+        assert_eq!(horner_reciprocal(1e20, &[0.; 4]), 0.);
+    }
+
+    #[test]
+    fn test_trim_zeros() {
+        use super::trim_zeros;
+        assert_eq!(trim_zeros(&[]), &[] as &[f64]);
+        assert_eq!(trim_zeros(&[0., 0.]), &[] as &[f64]);
+        assert_eq!(trim_zeros(&[0., 1., 0., -0.]), &[0., 1.]);
+        assert_eq!(trim_zeros(&[1., 2.]), &[1., 2.]);
+    }
+
+    #[test]
+    fn test_horner_desc() {
+        use super::horner_desc;
+        use crate::horner;
+        for x in -16..16 {
+            assert_eq!(horner_desc(x, &[]), 0);
+            assert_eq!(horner_desc(x, &[5]), 5);
+            let coeffs = [1, -2, 3, -4, 5, -6];
+            let mut reversed = coeffs;
+            reversed.reverse();
+            assert_eq!(horner_desc(x, &coeffs), horner(x, &reversed));
+            let x = x as f64 / 4.;
+            let coeffs = [0.5, 1., -2., 0.25];
+            assert_eq!(horner_desc(x, &coeffs), horner(x, &[0.25, -2., 1., 0.5]));
+        }
+    }
+
+    #[test]
+    fn test_looks_reversed() {
+        use super::looks_reversed;
+        // 1/k!
+        let mut series = [1.; 8];
+        for k in 1..series.len() {
+            series[k] = series[k - 1] / k as f64;
+        }
+        let mut reversed = series;
+        reversed.reverse();
+        for x in [0.5, 2., 10., -10.] {
+            assert!(!looks_reversed(x, &series));
+            assert!(!looks_reversed(x, &[]));
+            assert!(!looks_reversed(x, &[1e-9, 1e9]));
+            // not monotonic
+            assert!(!looks_reversed(x, &[1e-9, 1., 0.5, 1e9]));
+        }
+        assert!(looks_reversed(10., &reversed));
+        assert!(looks_reversed(-10., &reversed));
+        // at small points the values are not so different
+        assert!(!looks_reversed(0.1, &reversed));
+    }
+
+    #[test]
+    fn test_horner_checked_order() {
+        use super::horner_checked_order;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            let coeffs = [1., 2., 3., 4., 5.];
+            assert_eq!(horner_checked_order(x, &coeffs), horner(x, &coeffs));
+        }
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "listed from highest order to lowest")]
+    fn test_horner_checked_order_reversed() {
+        use super::horner_checked_order;
+        horner_checked_order(100., &[1., 10., 100., 1000., 10000.]);
+    }
+}
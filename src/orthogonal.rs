@@ -0,0 +1,647 @@
+//! Evaluation of series in bases of orthogonal polynomials.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::mem;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Evaluate a series of functions satisfying a three-term recurrence
+/// with [Clenshaw's algorithm](https://en.wikipedia.org/wiki/Clenshaw_algorithm).
+///
+/// Computes `c₀φ₀(x) + c₁φ₁(x) + ... + cₙφₙ(x)`, where the functions satisfy the recurrence
+/// `φₖ₊₁(x) = αₖ(x) φₖ(x) + βₖ(x) φₖ₋₁(x)` with `φ₀(x) = 1` and `φ₋₁(x) = 0`,
+/// and the closures `alpha(k, &x)` and `beta(k, &x)` return `αₖ(x)` and `βₖ(x)`.
+/// The value `β₀(x)` is never used.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// For instance:
+///
+/// - [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials) of the first kind
+///   have `α₀(x) = x`, `αₖ(x) = 2x` for `k > 0`, and `βₖ(x) = -1`, the same as [`chebyshev_eval`];
+/// - [Legendre polynomials](https://en.wikipedia.org/wiki/Legendre_polynomials) have
+///   `αₖ(x) = (2k+1) x / (k+1)` and `βₖ(x) = -k / (k+1)`, the same as [`legendre_eval`];
+/// - probabilists' [Hermite polynomials](https://en.wikipedia.org/wiki/Hermite_polynomials) have
+///   `αₖ(x) = x` and `βₖ(x) = -k`, the same as [`hermite_eval`];
+/// - monomials have `αₖ(x) = x` and `βₖ(x) = 0`, which reduces to [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::clenshaw;
+///
+/// // Chebyshev polynomials of the first kind
+/// let chebyshev = |coeffs: &[f64], x: f64| {
+///     clenshaw(
+///         coeffs,
+///         |k, &x: &f64| if k == 0 { x } else { 2.0 * x },
+///         |_, _| -1.0,
+///         x,
+///     )
+/// };
+///
+/// let x = 3.0;
+/// // T₀(x) = 1, T₁(x) = x, T₂(x) = 2x² - 1
+/// assert_eq!(chebyshev(&[2.0, 3.0, 4.0], x), 2.0 + 3.0 * x + 4.0 * (2.0 * x * x - 1.0));
+///
+/// // Legendre polynomials
+/// let legendre = |coeffs: &[f64], x: f64| {
+///     clenshaw(
+///         coeffs,
+///         |k, &x: &f64| (2 * k + 1) as f64 * x / (k + 1) as f64,
+///         |k, _| -(k as f64) / (k + 1) as f64,
+///         x,
+///     )
+/// };
+///
+/// // P₂(x) = (3x² - 1) / 2
+/// assert_eq!(legendre(&[0.0, 0.0, 1.0], x), (3.0 * x * x - 1.0) / 2.0);
+/// ```
+#[track_caller]
+pub fn clenshaw<T, A, B>(coeffs: &[T], alpha: A, beta: B, x: T) -> T
+where
+    T: Clone + Zero + Add<Output = T> + Mul<Output = T>,
+    A: Fn(usize, &T) -> T,
+    B: Fn(usize, &T) -> T,
+{
+    // b1 = bₖ₊₁, b2 = bₖ₊₂
+    let (mut b1, mut b2) = (T::zero(), T::zero());
+    for (k, c) in coeffs.iter().enumerate().rev() {
+        let b0 = c.clone() + alpha(k, &x) * b1.clone() + beta(k + 1, &x) * b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    b1
+}
+
+/// Evaluate a series of [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials)
+/// of the first kind with [Clenshaw's algorithm](https://en.wikipedia.org/wiki/Clenshaw_algorithm).
+///
+/// Computes `c₀T₀(x) + c₁T₁(x) + ... + cₙTₙ(x)`, where the polynomials satisfy the recurrence
+/// `Tₖ₊₁(x) = 2x Tₖ(x) - Tₖ₋₁(x)` with `T₀(x) = 1` and `T₁(x) = x`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+#[cfg_attr(
+    feature = "alloc",
+    doc = "Coefficients in the monomial basis can be converted with [`monomial_to_chebyshev`].\n"
+)]
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::chebyshev_eval;
+///
+/// let x = 3.0;
+///
+/// assert_eq!(chebyshev_eval(x, &[]), 0.0);
+/// assert_eq!(chebyshev_eval(x, &[2.0]), 2.0);
+///
+/// assert_eq!(
+///     chebyshev_eval(x, &[2.0, 3.0, 4.0]),
+///     2.0 + 3.0 * x + 4.0 * (2.0 * x * x - 1.0)
+/// );
+/// ```
+pub fn chebyshev_eval(x: f64, coeffs: &[f64]) -> f64 {
+    let Some((&c0, rest)) = coeffs.split_first() else {
+        return 0.0;
+    };
+    // b1 = bₖ₊₁, b2 = bₖ₊₂
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &c in rest.iter().rev() {
+        let b0 = c + 2.0 * x * b1 - b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    // the last step has α₀ = x instead of 2x
+    c0 + x * b1 - b2
+}
+
+/// Convert the coefficients of a polynomial from the monomial basis to the basis of
+/// [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials) of the first kind.
+///
+/// The coefficients are listed from zeroth order to highest, in both bases, and there are as
+/// many coefficients in the output as in the input.
+///
+/// The conversion runs Horner's method in the Chebyshev basis, multiplying by `x` with
+/// `x T₀ = T₁` and `x Tₖ = (Tₖ₋₁ + Tₖ₊₁) / 2`, in `O(n²)` operations. The Chebyshev coefficients
+/// are at most as large as the monomial ones (in sum of absolute values), so this direction is
+/// well-conditioned. The inverse is [`chebyshev_to_monomial`].
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{chebyshev_eval, horner, monomial_to_chebyshev};
+///
+/// // x³ = (3 T₁ + T₃) / 4
+/// assert_eq!(monomial_to_chebyshev(&[0.0, 0.0, 0.0, 1.0]), [0.0, 0.75, 0.0, 0.25]);
+///
+/// let coeffs = [2.0, 3.0, 4.0];
+/// let x = 0.5;
+/// assert_eq!(chebyshev_eval(x, &monomial_to_chebyshev(&coeffs)), horner(x, &coeffs));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn monomial_to_chebyshev(coeffs: &[f64]) -> Vec<f64> {
+    let n = coeffs.len();
+    let mut acc = vec![0.0; n];
+    let mut prod = vec![0.0; n];
+    for (d, &c) in coeffs.iter().rev().enumerate() {
+        // `acc` has degree less than `d`, and `prod = x * acc` degree at most `d`
+        prod[..=d].fill(0.0);
+        if d > 0 {
+            prod[1] += acc[0];
+        }
+        for j in 1..d {
+            prod[j - 1] += 0.5 * acc[j];
+            prod[j + 1] += 0.5 * acc[j];
+        }
+        prod[0] += c;
+        mem::swap(&mut acc, &mut prod);
+    }
+    acc
+}
+
+/// Convert the coefficients of a polynomial from the basis of
+/// [Chebyshev polynomials](https://en.wikipedia.org/wiki/Chebyshev_polynomials) of the first kind
+/// to the monomial basis.
+///
+/// The coefficients are listed from zeroth order to highest, in both bases, and there are as
+/// many coefficients in the output as in the input.
+///
+/// The monomial coefficients of every `Tₖ` are generated with the recurrence
+/// `Tₖ₊₁ = 2x Tₖ - Tₖ₋₁` and accumulated, in `O(n²)` operations. This is the inverse of
+/// [`monomial_to_chebyshev`], but it is ill-conditioned for high degrees: the leading coefficient
+/// of `Tₖ` is `2ᵏ⁻¹`, so the monomial coefficients can be much larger than the Chebyshev ones
+/// and the rounding errors of the evaluation grow accordingly.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{chebyshev_eval, chebyshev_to_monomial, horner};
+///
+/// // T₃ = 4x³ - 3x
+/// assert_eq!(chebyshev_to_monomial(&[0.0, 0.0, 0.0, 1.0]), [0.0, -3.0, 0.0, 4.0]);
+///
+/// let coeffs = [2.0, 3.0, 4.0];
+/// let x = 0.5;
+/// assert_eq!(horner(x, &chebyshev_to_monomial(&coeffs)), chebyshev_eval(x, &coeffs));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn chebyshev_to_monomial(coeffs: &[f64]) -> Vec<f64> {
+    let n = coeffs.len();
+    let mut out = vec![0.0; n];
+    // monomial coefficients of `Tₖ₋₁` and `Tₖ`
+    let mut prev = vec![0.0; n];
+    let mut cur = vec![0.0; n];
+    if let Some(t) = cur.first_mut() {
+        *t = 1.0;
+    }
+    for (k, &c) in coeffs.iter().enumerate() {
+        for (o, &t) in out.iter_mut().zip(&cur[..=k]) {
+            *o += c * t;
+        }
+        if k + 1 < n {
+            // Tₖ₊₁ = 2x Tₖ - Tₖ₋₁, except T₁ = x T₀, overwriting `Tₖ₋₁`
+            let factor = if k == 0 { 1.0 } else { 2.0 };
+            prev[0] = -prev[0];
+            for j in 1..=k + 1 {
+                prev[j] = factor * cur[j - 1] - prev[j];
+            }
+            mem::swap(&mut prev, &mut cur);
+        }
+    }
+    out
+}
+
+/// Evaluate a series of probabilists' [Hermite polynomials](https://en.wikipedia.org/wiki/Hermite_polynomials)
+/// with [Clenshaw's algorithm](https://en.wikipedia.org/wiki/Clenshaw_algorithm).
+///
+/// Computes `c₀He₀(x) + c₁He₁(x) + ... + cₙHeₙ(x)`, where the polynomials satisfy the recurrence
+/// `Heₖ₊₁(x) = x Heₖ(x) - k Heₖ₋₁(x)` with `He₀(x) = 1` and `He₁(x) = x`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::hermite_eval;
+///
+/// let x = 3.0;
+///
+/// assert_eq!(hermite_eval(x, &[]), 0.0);
+/// assert_eq!(hermite_eval(x, &[2.0]), 2.0);
+///
+/// assert_eq!(
+///     hermite_eval(x, &[2.0, 3.0, 4.0]),
+///     2.0 + 3.0 * x + 4.0 * (x * x - 1.0)
+/// );
+/// ```
+pub fn hermite_eval(x: f64, coeffs: &[f64]) -> f64 {
+    // b1 = bₖ₊₁, b2 = bₖ₊₂
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        let b0 = c + x * b1 - (k + 1) as f64 * b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    b1
+}
+
+/// Evaluate a series of [Legendre polynomials](https://en.wikipedia.org/wiki/Legendre_polynomials)
+/// with [Clenshaw's algorithm](https://en.wikipedia.org/wiki/Clenshaw_algorithm).
+///
+/// Computes `c₀P₀(x) + c₁P₁(x) + ... + cₙPₙ(x)`, where the polynomials satisfy the recurrence
+/// `(k+1) Pₖ₊₁(x) = (2k+1) x Pₖ(x) - k Pₖ₋₁(x)` with `P₀(x) = 1` and `P₁(x) = x`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::legendre_eval;
+///
+/// let x = 3.0;
+///
+/// assert_eq!(legendre_eval(x, &[]), 0.0);
+/// assert_eq!(legendre_eval(x, &[2.0]), 2.0);
+///
+/// assert_eq!(
+///     legendre_eval(x, &[2.0, 3.0, 4.0]),
+///     2.0 + 3.0 * x + 4.0 * (3.0 * x * x - 1.0) / 2.0
+/// );
+/// ```
+pub fn legendre_eval(x: f64, coeffs: &[f64]) -> f64 {
+    // b1 = bₖ₊₁, b2 = bₖ₊₂
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        let k = k as f64;
+        let alpha = (2. * k + 1.) / (k + 1.) * x;
+        let beta = -(k + 1.) / (k + 2.);
+        let b0 = c + alpha * b1 + beta * b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    b1
+}
+
+/// Evaluate a cosine series with the [Goertzel algorithm](https://en.wikipedia.org/wiki/Goertzel_algorithm).
+///
+/// Computes `a₀ + a₁ cos θ + a₂ cos 2θ + ... + aₙ cos nθ` with the recurrence
+/// `bₖ = aₖ + 2 cos θ bₖ₊₁ - bₖ₊₂`, that is [Clenshaw's algorithm](https://en.wikipedia.org/wiki/Clenshaw_algorithm)
+/// for the Chebyshev polynomials `cos kθ = Tₖ(cos θ)`. Only one cosine is computed, instead of
+/// one for every term.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The recurrence loses accuracy when `θ` is close to a multiple of `π`, where the rounding
+/// errors are amplified by up to a factor `n²`; elsewhere it is about as accurate as the direct sum.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::goertzel_cosine;
+///
+/// let theta: f64 = 0.3;
+/// let sum = 2.0 + 3.0 * theta.cos() + 4.0 * (2.0 * theta).cos();
+///
+/// assert!((goertzel_cosine(theta, &[2.0, 3.0, 4.0]) - sum).abs() < 1e-14);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn goertzel_cosine(theta: f64, coeffs: &[f64]) -> f64 {
+    let cos = num_traits::Float::cos(theta);
+    let (b1, b2) = goertzel(2.0 * cos, coeffs);
+    // the last step has α₀ = cos θ instead of 2 cos θ
+    b1 - cos * b2
+}
+
+/// Evaluate a sine series with the [Goertzel algorithm](https://en.wikipedia.org/wiki/Goertzel_algorithm).
+///
+/// Computes `a₁ sin θ + a₂ sin 2θ + ... + aₙ sin nθ` with the same recurrence as
+/// [`goertzel_cosine`]. The coefficient of order zero multiplies `sin 0 = 0`, hence it has no effect.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::goertzel_sine;
+///
+/// let theta: f64 = 0.3;
+/// let sum = 3.0 * theta.sin() + 4.0 * (2.0 * theta).sin();
+///
+/// assert!((goertzel_sine(theta, &[2.0, 3.0, 4.0]) - sum).abs() < 1e-14);
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn goertzel_sine(theta: f64, coeffs: &[f64]) -> f64 {
+    let (sin, cos) = num_traits::Float::sin_cos(theta);
+    let rest = coeffs.get(1..).unwrap_or_default();
+    // the series of sin (k+1)θ / sin θ = Uₖ(cos θ)
+    let (b1, _) = goertzel(2.0 * cos, rest);
+    sin * b1
+}
+
+/// Runs the recurrence `bₖ = aₖ + two_cos bₖ₊₁ - bₖ₊₂` down to `k = 0`, returning `(b₀, b₁)`.
+#[cfg(any(feature = "std", feature = "libm"))]
+fn goertzel(two_cos: f64, coeffs: &[f64]) -> (f64, f64) {
+    // b1 = bₖ₊₁, b2 = bₖ₊₂
+    let (mut b1, mut b2) = (0.0, 0.0);
+    for &c in coeffs.iter().rev() {
+        let b0 = c + two_cos * b1 - b2;
+        b2 = b1;
+        b1 = b0;
+    }
+    (b1, b2)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_clenshaw() {
+        use super::{clenshaw, hermite_eval, legendre_eval};
+        use crate::horner;
+        let legendre = |coeffs: &[f64], x: f64| {
+            clenshaw(
+                coeffs,
+                |k, &x: &f64| (2 * k + 1) as f64 * x / (k + 1) as f64,
+                |k, _| -(k as f64) / (k + 1) as f64,
+                x,
+            )
+        };
+        for x in -16..=16 {
+            let x = x as f64 / 16.;
+            let p = [
+                1.,
+                x,
+                (3. * x * x - 1.) / 2.,
+                (5. * x * x * x - 3. * x) / 2.,
+                (35. * x * x * x * x - 30. * x * x + 3.) / 8.,
+                (63. * x * x * x * x * x - 70. * x * x * x + 15. * x) / 8.,
+                (231. * x * x * x * x * x * x - 315. * x * x * x * x + 105. * x * x - 5.) / 16.,
+            ];
+            assert_eq!(legendre(&[], x), 0.);
+            assert_eq!(legendre(&[5.], x), 5.);
+            for (k, &p_k) in p.iter().enumerate() {
+                let mut coeffs = [0.; 7];
+                coeffs[k] = 1.;
+                assert_close(legendre(&coeffs[..=k], x), p_k);
+                assert_close(legendre(&coeffs, x), p_k);
+            }
+            let coeffs = [1., 2., 3., 4., 5., 6., 7.];
+            assert_close(legendre(&coeffs, x), legendre_eval(x, &coeffs));
+            assert_close(
+                legendre(&coeffs, x),
+                p.iter()
+                    .zip(1..)
+                    .map(|(p_k, c)| c as f64 * p_k)
+                    .sum::<f64>(),
+            );
+        }
+        for x in -8..8 {
+            let coeffs = [1, -2, 3, -4, 5];
+            // monomials
+            assert_eq!(
+                clenshaw(&coeffs, |_, &x| x, |_, _| 0, x),
+                horner(x, &coeffs)
+            );
+            // Hermite
+            let coeffs = coeffs.map(|c| c as f64);
+            let x = x as f64;
+            assert_eq!(
+                clenshaw(&coeffs, |_, &x| x, |k, _| -(k as f64), x),
+                hermite_eval(x, &coeffs)
+            );
+        }
+    }
+
+    #[test]
+    fn test_chebyshev_eval() {
+        use super::{chebyshev_eval, clenshaw};
+        for x in -16..=16 {
+            let x = x as f64 / 16.;
+            let t = [
+                1.,
+                x,
+                2. * x * x - 1.,
+                4. * x * x * x - 3. * x,
+                8. * x * x * x * x - 8. * x * x + 1.,
+                16. * x * x * x * x * x - 20. * x * x * x + 5. * x,
+            ];
+            assert_eq!(chebyshev_eval(x, &[]), 0.);
+            assert_eq!(chebyshev_eval(x, &[5.]), 5.);
+            for (k, &t_k) in t.iter().enumerate() {
+                let mut coeffs = [0.; 6];
+                coeffs[k] = 1.;
+                assert_close(chebyshev_eval(x, &coeffs[..=k]), t_k);
+                assert_close(chebyshev_eval(x, &coeffs), t_k);
+            }
+            let coeffs = [1., 2., 3., 4., 5., 6.];
+            assert_close(
+                chebyshev_eval(x, &coeffs),
+                t.iter()
+                    .zip(1..)
+                    .map(|(t_k, c)| c as f64 * t_k)
+                    .sum::<f64>(),
+            );
+            assert_eq!(
+                chebyshev_eval(x, &coeffs),
+                clenshaw(
+                    &coeffs,
+                    |k, &x: &f64| if k == 0 { x } else { 2. * x },
+                    |_, _| -1.,
+                    x,
+                )
+            );
+        }
+        // Tₖ(cos θ) = cos kθ for θ = 0, π/2, π
+        let mut coeffs = [0.; 32];
+        for k in 0..coeffs.len() {
+            coeffs[k] = 1.;
+            let sign = if k % 2 == 0 { 1. } else { -1. };
+            assert_close(chebyshev_eval(1., &coeffs), 1.);
+            assert_close(chebyshev_eval(-1., &coeffs), sign);
+            assert_close(chebyshev_eval(0., &coeffs), [1., 0., -1., 0.][k % 4]);
+            coeffs[k] = 0.;
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_chebyshev_conversion() {
+        use super::{chebyshev_eval, chebyshev_to_monomial, monomial_to_chebyshev};
+        use crate::horner;
+        assert!(monomial_to_chebyshev(&[]).is_empty());
+        assert!(chebyshev_to_monomial(&[]).is_empty());
+        assert_eq!(monomial_to_chebyshev(&[3.]), [3.]);
+        assert_eq!(chebyshev_to_monomial(&[3.]), [3.]);
+        // x² = (T₀ + T₂) / 2 and x⁴ = (3 T₀ + 4 T₂ + T₄) / 8
+        assert_eq!(monomial_to_chebyshev(&[0., 0., 1.]), [0.5, 0., 0.5]);
+        assert_eq!(
+            monomial_to_chebyshev(&[0., 0., 0., 0., 1.]),
+            [0.375, 0., 0.5, 0., 0.125]
+        );
+        // T₄ = 8x⁴ - 8x² + 1 and T₅ = 16x⁵ - 20x³ + 5x
+        assert_eq!(
+            chebyshev_to_monomial(&[0., 0., 0., 0., 1.]),
+            [1., 0., -8., 0., 8.]
+        );
+        assert_eq!(
+            chebyshev_to_monomial(&[0., 0., 0., 0., 0., 1.]),
+            [0., 5., 0., -20., 0., 16.]
+        );
+        let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+        let mut random = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 2001) as f64 / 1000. - 1.
+        };
+        for n in 0..=12 {
+            let coeffs: Vec<f64> = (0..n).map(|_| random()).collect();
+            let chebyshev = monomial_to_chebyshev(&coeffs);
+            let monomial = chebyshev_to_monomial(&coeffs);
+            assert_eq!(chebyshev.len(), n);
+            assert_eq!(monomial.len(), n);
+            for x in -16..=16 {
+                let x = x as f64 / 16.;
+                assert_close(chebyshev_eval(x, &chebyshev), horner(x, &coeffs));
+                assert_close(horner(x, &monomial), chebyshev_eval(x, &coeffs));
+            }
+            // the round trips give back the coefficients
+            for (a, b) in chebyshev_to_monomial(&chebyshev).iter().zip(&coeffs) {
+                assert_close(*a, *b);
+            }
+            for (a, b) in monomial_to_chebyshev(&monomial).iter().zip(&coeffs) {
+                assert_close(*a, *b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hermite_eval() {
+        use super::hermite_eval;
+        for x in -16..16 {
+            let x = x as f64;
+            let he = [
+                1.,
+                x,
+                x * x - 1.,
+                x * x * x - 3. * x,
+                x * x * x * x - 6. * x * x + 3.,
+                x * x * x * x * x - 10. * x * x * x + 15. * x,
+            ];
+            assert_eq!(hermite_eval(x, &[]), 0.);
+            assert_eq!(hermite_eval(x, &[5.]), 5.);
+            for (k, &he_k) in he.iter().enumerate() {
+                let mut coeffs = [0.; 6];
+                coeffs[k] = 1.;
+                assert_eq!(hermite_eval(x, &coeffs[..=k]), he_k);
+                assert_eq!(hermite_eval(x, &coeffs), he_k);
+            }
+            assert_eq!(
+                hermite_eval(x, &[1., 2., 3., 4., 5., 6.]),
+                he.iter()
+                    .zip(1..)
+                    .map(|(he_k, c)| c as f64 * he_k)
+                    .sum::<f64>()
+            );
+        }
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!(
+            (a - b).abs() <= 1e-12 * b.abs().max(1.),
+            "{a} is not close to {b}"
+        );
+    }
+
+    #[test]
+    fn test_legendre_eval() {
+        use super::legendre_eval;
+        for x in -16..=16 {
+            let x = x as f64 / 16.;
+            let p = [
+                1.,
+                x,
+                (3. * x * x - 1.) / 2.,
+                (5. * x * x * x - 3. * x) / 2.,
+                (35. * x * x * x * x - 30. * x * x + 3.) / 8.,
+                (63. * x * x * x * x * x - 70. * x * x * x + 15. * x) / 8.,
+            ];
+            assert_eq!(legendre_eval(x, &[]), 0.);
+            assert_eq!(legendre_eval(x, &[5.]), 5.);
+            for (k, &p_k) in p.iter().enumerate() {
+                let mut coeffs = [0.; 6];
+                coeffs[k] = 1.;
+                assert_close(legendre_eval(x, &coeffs[..=k]), p_k);
+                assert_close(legendre_eval(x, &coeffs), p_k);
+            }
+            assert_close(
+                legendre_eval(x, &[1., 2., 3., 4., 5., 6.]),
+                p.iter()
+                    .zip(1..)
+                    .map(|(p_k, c)| c as f64 * p_k)
+                    .sum::<f64>(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_legendre_eval_endpoints() {
+        use super::legendre_eval;
+        let mut coeffs = [0.; 32];
+        for k in 0..coeffs.len() {
+            coeffs[k] = 1.;
+            let sign = if k % 2 == 0 { 1. } else { -1. };
+            assert_close(legendre_eval(1., &coeffs), 1.);
+            assert_close(legendre_eval(-1., &coeffs), sign);
+            coeffs[k] = 0.;
+        }
+    }
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    #[test]
+    fn test_goertzel() {
+        use super::{goertzel_cosine, goertzel_sine};
+        use num_traits::Float;
+        let mut state = 0x853c_49e6_748f_ea9b_u64;
+        let mut next = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state >> 11) as f64 / (1u64 << 53) as f64 * 2. - 1.
+        };
+        assert_eq!(goertzel_cosine(0.5, &[]), 0.);
+        assert_eq!(goertzel_sine(0.5, &[]), 0.);
+        assert_eq!(goertzel_cosine(0.5, &[3.]), 3.);
+        assert_eq!(goertzel_sine(0.5, &[3.]), 0.);
+        for n in 1..40 {
+            let coeffs: [f64; 40] = core::array::from_fn(|_| next());
+            let coeffs = &coeffs[..n];
+            for j in -20..=20 {
+                let theta = j as f64 * 0.3 + 0.1;
+                let (cos, sin) = coeffs
+                    .iter()
+                    .enumerate()
+                    .fold((0., 0.), |(cos, sin), (k, &a)| {
+                        let (s, c) = Float::sin_cos(k as f64 * theta);
+                        (cos + a * c, sin + a * s)
+                    });
+                let tol = 1e-13 * (n * n) as f64;
+                assert!((goertzel_cosine(theta, coeffs) - cos).abs() <= tol);
+                assert!((goertzel_sine(theta, coeffs) - sin).abs() <= tol);
+            }
+        }
+        // cos kθ and sin kθ alone
+        let mut coeffs = [0.; 8];
+        for k in 0..8 {
+            coeffs[k] = 1.;
+            let theta: f64 = 1.3;
+            let (s, c) = Float::sin_cos(k as f64 * theta);
+            assert!((goertzel_cosine(theta, &coeffs) - c).abs() < 1e-14);
+            assert!((goertzel_sine(theta, &coeffs) - s).abs() < 1e-14);
+            coeffs[k] = 0.;
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Parallel evaluation with [`rayon`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+use rayon::prelude::*;
+
+use crate::{horner, horner_many};
+
+/// Number of points evaluated by every task of [`horner_par`].
+const CHUNK: usize = 4096;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at many points, in parallel.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns the values at the points `xs`, in the same order. The points are split into chunks
+/// which are evaluated in parallel on the [`rayon`] thread pool, each one with
+/// [`horner_many`](fn@crate::horner_many), hence the result is identical to the serial one.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_par;
+///
+/// assert_eq!(horner_par(&[0.0, 1.0, 7.0], &[2.0, 3.0, 4.0]), [2.0, 9.0, 219.0]);
+/// ```
+pub fn horner_par(xs: &[f64], coeffs: &[f64]) -> Vec<f64> {
+    let mut values = vec![0.0; xs.len()];
+    values
+        .par_chunks_mut(CHUNK)
+        .zip(xs.par_chunks(CHUNK))
+        .for_each(|(values, xs)| horner_many(xs, coeffs, values));
+    values
+}
+
+/// Evaluate a polynomial in two variables on a grid, in parallel.
+///
+/// The coefficients are laid out as in [`horner_2d`](fn@crate::horner_2d),
+/// i.e. `coeffs[i][j]` is the coefficient of `xⁱ yʲ`.
+///
+/// Returns the values at all the points `(x, y)` of the grid `xs × ys`, in row-major order
+/// with one row per `y` and one column per `x`: the value at `(xs[c], ys[r])` is at index
+/// `r * xs.len() + c`. The rows are evaluated in parallel; within a row the coefficients are
+/// first collapsed to a polynomial in `x`, so the result is the same as the one of
+/// [`horner_2d`](fn@crate::horner_2d).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_2d_grid_par;
+///
+/// // 1 + xy
+/// let coeffs: [&[f64]; 2] = [&[1.0], &[0.0, 1.0]];
+///
+/// assert_eq!(
+///     horner_2d_grid_par(&[1.0, 2.0, 3.0], &[10.0, 20.0], &coeffs),
+///     [11.0, 21.0, 31.0, 21.0, 41.0, 61.0]
+/// );
+/// ```
+pub fn horner_2d_grid_par(xs: &[f64], ys: &[f64], coeffs: &[&[f64]]) -> Vec<f64> {
+    let mut values = vec![0.0; xs.len() * ys.len()];
+    if xs.is_empty() {
+        return values;
+    }
+    values
+        .par_chunks_mut(xs.len())
+        .zip(ys.par_iter())
+        .for_each(|(row, &y)| {
+            let coeffs_x: Vec<f64> = coeffs.iter().map(|coeffs_y| horner(y, coeffs_y)).collect();
+            for (value, &x) in row.iter_mut().zip(xs) {
+                *value = horner(x, &coeffs_x);
+            }
+        });
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_par() {
+        use super::{horner_par, CHUNK};
+        use crate::horner_many;
+        let coeffs: Vec<f64> = (0..51).map(|k| (-1f64).powi(k) / (k + 1) as f64).collect();
+        for n in [0, 1, 100, CHUNK, 3 * CHUNK + 17] {
+            let xs: Vec<f64> = (0..n).map(|k| k as f64 / n as f64 * 2. - 1.).collect();
+            let mut expected = vec![0.; n];
+            horner_many(&xs, &coeffs, &mut expected);
+            assert_eq!(horner_par(&xs, &coeffs), expected);
+        }
+        assert_eq!(horner_par(&[1., 2.], &[]), [0., 0.]);
+    }
+
+    #[test]
+    fn test_horner_2d_grid_par() {
+        use super::horner_2d_grid_par;
+        use crate::horner_2d;
+        let coeffs: [&[f64]; 4] = [&[1., -2., 3.], &[0.5], &[4., 5., -0.25], &[-6.]];
+        let xs: Vec<f64> = (0..37).map(|k| k as f64 / 8. - 2.).collect();
+        let ys: Vec<f64> = (0..23).map(|k| k as f64 / 4. - 3.).collect();
+        let values = horner_2d_grid_par(&xs, &ys, &coeffs);
+        assert_eq!(values.len(), xs.len() * ys.len());
+        for (r, &y) in ys.iter().enumerate() {
+            for (c, &x) in xs.iter().enumerate() {
+                assert_eq!(values[r * xs.len() + c], horner_2d(x, y, &coeffs));
+            }
+        }
+        assert!(horner_2d_grid_par(&[], &ys, &coeffs).is_empty());
+        assert!(horner_2d_grid_par(&xs, &[], &coeffs).is_empty());
+        assert_eq!(
+            horner_2d_grid_par(&xs, &ys, &[]),
+            vec![0.; xs.len() * ys.len()]
+        );
+    }
+}
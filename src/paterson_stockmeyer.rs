@@ -0,0 +1,155 @@
+//! Evaluation with the Paterson–Stockmeyer algorithm.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Evaluate a polynomial with the [Paterson–Stockmeyer algorithm](https://doi.org/10.1137/0202007),
+/// which minimizes the number of multiplications between powers of `x`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The `n` coefficients are split into blocks of `m = ⌈√n⌉` consecutive ones. The "baby step"
+/// powers `x², ..., xᵐ` are computed once, every block is evaluated as a linear combination of
+/// them, and the blocks are combined with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// in the "giant step" `xᵐ`.
+///
+/// This takes about `2√n` multiplications of two powers of `x` (or of the accumulator by `xᵐ`),
+/// and `n` multiplications of a coefficient by a power, whereas [`horner`](fn@crate::horner) takes
+/// `n` multiplications of the accumulator by `x`. When `x` is a matrix with scalar coefficients
+/// the latter are much cheaper than the former, so for large `n` this is a big saving; for
+/// scalar `x` it is slightly more expensive than Horner's method, and the result may differ from
+/// it due to rounding.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::paterson_stockmeyer;
+///
+/// let x = 7;
+///
+/// assert_eq!(paterson_stockmeyer(x, &[]), 0);
+/// assert_eq!(
+///     paterson_stockmeyer(x, &[2, 3, 4, 5, 6]),
+///     2 + x * (3 + x * (4 + x * (5 + x * 6)))
+/// );
+/// ```
+#[track_caller]
+pub fn paterson_stockmeyer<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    let n = coeffs.len();
+    if n <= 1 {
+        return coeffs.first().cloned().unwrap_or_else(T::zero);
+    }
+    let m = n.isqrt() + usize::from(n.isqrt() * n.isqrt() < n);
+    // powers[j] = xʲ⁺¹ for the baby steps
+    let mut powers = Vec::with_capacity(m - 1);
+    powers.push(x);
+    for j in 1..m - 1 {
+        let next = powers[j - 1].clone() * &powers[0];
+        powers.push(next);
+    }
+    let block = |chunk: &[T]| {
+        let mut acc = chunk[0].clone();
+        for (c, power) in chunk[1..].iter().zip(&powers) {
+            acc = acc + &(c.clone() * power);
+        }
+        acc
+    };
+    let mut blocks = coeffs.chunks(m).rev();
+    let mut acc = block(blocks.next().unwrap());
+    if n > m {
+        let giant = powers[m - 2].clone() * &powers[0];
+        for chunk in blocks {
+            acc = acc * &giant + &block(chunk);
+        }
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_paterson_stockmeyer() {
+        use super::paterson_stockmeyer;
+        use crate::horner;
+        let coeffs: Vec<i64> = (0..31).map(|k| (k % 7) - 3).collect();
+        for x in -3..=3 {
+            for n in 0..=coeffs.len() {
+                assert_eq!(
+                    paterson_stockmeyer(x, &coeffs[..n]),
+                    horner(x, &coeffs[..n]),
+                    "x = {x}, n = {n}"
+                );
+            }
+        }
+        let coeffs: Vec<f64> = (0..31).map(|k| 1. / (k + 1) as f64).collect();
+        for x in -8..=8 {
+            let x = x as f64 / 8.;
+            for n in 0..=coeffs.len() {
+                let expected = horner(x, &coeffs[..n]);
+                let y = paterson_stockmeyer(x, &coeffs[..n]);
+                assert!((y - expected).abs() <= 1e-14 * expected.abs().max(1.));
+            }
+        }
+    }
+
+    #[test]
+    fn test_paterson_stockmeyer_multiplications() {
+        use super::paterson_stockmeyer;
+        use core::cell::Cell;
+        use core::ops::{Add, Mul};
+        use num_traits::Zero;
+
+        // counts the multiplications of two values of degree > 0, as for matrices,
+        // while the coefficients are constants of degree 0
+        #[derive(Clone, Copy)]
+        struct Counted<'a>(i64, bool, &'a Cell<usize>);
+
+        impl Add<&Self> for Counted<'_> {
+            type Output = Self;
+            fn add(self, rhs: &Self) -> Self {
+                Counted(self.0 + rhs.0, self.1 || rhs.1, self.2)
+            }
+        }
+        impl Mul<&Self> for Counted<'_> {
+            type Output = Self;
+            fn mul(self, rhs: &Self) -> Self {
+                if self.1 && rhs.1 {
+                    self.2.set(self.2.get() + 1);
+                }
+                Counted(self.0 * rhs.0, self.1 || rhs.1, self.2)
+            }
+        }
+        impl Zero for Counted<'_> {
+            fn zero() -> Self {
+                unreachable!("only needed for the empty polynomial")
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+        impl Add for Counted<'_> {
+            type Output = Self;
+            fn add(self, rhs: Self) -> Self {
+                Counted(self.0 + rhs.0, self.1 || rhs.1, self.2)
+            }
+        }
+
+        let count = Cell::new(0);
+        for n in [4, 9, 16, 25, 30] {
+            let coeffs: Vec<_> = (0..n).map(|k| Counted(k % 3, false, &count)).collect();
+            count.set(0);
+            let y = paterson_stockmeyer(Counted(2, true, &count), &coeffs);
+            let expected = (0..n).rev().fold(0, |acc, k| acc * 2 + k % 3);
+            assert_eq!(y.0, expected);
+            let m = (n as f64).sqrt().ceil() as usize;
+            // baby steps x², ..., xᵐ and one giant step per block but the first
+            assert_eq!(count.get(), (m - 1) + (n as usize).div_ceil(m) - 1);
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Polynomials as an owned type.
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+use crate::{estrin, horner};
+
+/// Polynomial with owned coefficients, listed from zeroth order to highest.
+///
+/// This is a thin wrapper around a [`Vec`] of coefficients, providing method-style evaluation
+/// which delegates to the free functions of the crate.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::Polynomial;
+///
+/// let p = Polynomial::from(vec![2, 3, 4, 0]);
+///
+/// assert_eq!(p.eval(7), 2 + 7 * (3 + 7 * 4));
+/// assert_eq!(p.eval_estrin(7), p.eval(7));
+/// assert_eq!(p.degree(), Some(2));
+/// assert_eq!(p.coeffs(), [2, 3, 4, 0]);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Polynomial<T>(Vec<T>);
+
+impl<T> Polynomial<T> {
+    /// Returns the coefficients, listed from zeroth order to highest.
+    pub fn coeffs(&self) -> &[T] {
+        &self.0
+    }
+}
+
+impl<T: Zero> Polynomial<T> {
+    /// Returns the degree of the polynomial, ignoring the trailing zero coefficients,
+    /// or `None` for the zero polynomial.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polyeval::Polynomial;
+    ///
+    /// assert_eq!(Polynomial::from(vec![1.0, 0.0, 2.0, 0.0]).degree(), Some(2));
+    /// assert_eq!(Polynomial::from(vec![5.0]).degree(), Some(0));
+    /// assert_eq!(Polynomial::from(vec![0.0, 0.0]).degree(), None);
+    /// assert_eq!(Polynomial::<f64>::from(vec![]).degree(), None);
+    /// ```
+    pub fn degree(&self) -> Option<usize> {
+        self.0.iter().rposition(|c| !c.is_zero())
+    }
+}
+
+impl<T> Polynomial<T>
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    /// Evaluate the polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+    /// same as [`horner`](fn@crate::horner).
+    #[track_caller]
+    pub fn eval(&self, x: T) -> T {
+        horner(x, &self.0)
+    }
+
+    /// Evaluate the polynomial with [Estrin's scheme](https://en.wikipedia.org/wiki/Estrin%27s_scheme),
+    /// same as [`estrin`](fn@crate::estrin).
+    #[track_caller]
+    pub fn eval_estrin(&self, x: T) -> T
+    where
+        T: Clone,
+    {
+        estrin(x, &self.0)
+    }
+}
+
+impl<T> From<Vec<T>> for Polynomial<T> {
+    fn from(coeffs: Vec<T>) -> Self {
+        Polynomial(coeffs)
+    }
+}
+
+impl<T> FromIterator<T> for Polynomial<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Polynomial(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Polynomial;
+
+    #[test]
+    fn test_polynomial_eval() {
+        use crate::{estrin, horner};
+        let coeffs = vec![1, -2, 3, -4, 5];
+        let p = Polynomial::from(coeffs.clone());
+        let q: Polynomial<i32> = coeffs.iter().copied().collect();
+        assert_eq!(p, q);
+        assert_eq!(p.coeffs(), coeffs);
+        for x in -16..16 {
+            assert_eq!(p.eval(x), horner(x, &coeffs));
+            assert_eq!(p.eval_estrin(x), estrin(x, &coeffs));
+            assert_eq!(Polynomial::default().eval(x), 0);
+        }
+        let p: Polynomial<f64> = (0..5).map(|k| 1. / (k + 1) as f64).collect();
+        assert_eq!(p.eval(0.5), horner(0.5, p.coeffs()));
+    }
+
+    #[test]
+    fn test_polynomial_degree() {
+        assert_eq!(Polynomial::<i32>::default().degree(), None);
+        assert_eq!(Polynomial::from(vec![0]).degree(), None);
+        assert_eq!(Polynomial::from(vec![0, 0, 0]).degree(), None);
+        assert_eq!(Polynomial::from(vec![3]).degree(), Some(0));
+        assert_eq!(Polynomial::from(vec![0, 3]).degree(), Some(1));
+        assert_eq!(Polynomial::from(vec![1, 2, 3, 0, 0]).degree(), Some(2));
+        assert_eq!(Polynomial::from(vec![1, 0, 0, 4]).degree(), Some(3));
+        assert_eq!(Polynomial::from(vec![1., -0., 0.]).degree(), Some(0));
+    }
+}
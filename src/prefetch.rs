@@ -0,0 +1,107 @@
+//! Evaluation of very long polynomials with software prefetching.
+
+/// Number of coefficients in a cache line.
+const LINE: usize = 64 / core::mem::size_of::<f64>();
+
+/// How many coefficients ahead of the current position are prefetched (16 cache lines).
+const PREFETCH_DISTANCE: usize = 16 * LINE;
+
+#[cfg(all(feature = "prefetch", target_arch = "x86_64"))]
+#[inline(always)]
+fn prefetch(p: *const f64) {
+    use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+    // SAFETY: prefetching is only a hint and never faults, even on invalid addresses;
+    // `sse` is always available on `x86_64`.
+    unsafe { _mm_prefetch::<_MM_HINT_T0>(p.cast()) }
+}
+
+#[cfg(all(feature = "prefetch", target_arch = "x86"))]
+#[inline(always)]
+fn prefetch(p: *const f64) {
+    #[cfg(target_feature = "sse")]
+    {
+        use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+        // SAFETY: prefetching is only a hint and never faults, even on invalid addresses;
+        // `sse` is enabled by the surrounding `cfg`.
+        unsafe { _mm_prefetch::<_MM_HINT_T0>(p.cast()) }
+    }
+    #[cfg(not(target_feature = "sse"))]
+    let _ = p;
+}
+
+#[cfg(not(all(feature = "prefetch", any(target_arch = "x86", target_arch = "x86_64"))))]
+#[inline(always)]
+fn prefetch(_p: *const f64) {}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// issuing software prefetch hints for the coefficients.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// This is meant for polynomials with millions of coefficients streamed from memory, where the
+/// evaluation is dominated by cache misses. While folding backward through the slice, a prefetch
+/// hint is issued once per cache line for the coefficients 16 cache lines (128 coefficients) ahead.
+///
+/// It is purely a performance optimization: the result is identical to [`horner`](fn@crate::horner).
+/// The hints are emitted only with the `prefetch` feature on `x86` and `x86_64`;
+/// elsewhere they are omitted, and this function behaves exactly as [`horner`](fn@crate::horner).
+///
+/// Modern CPUs detect sequential streams with their hardware prefetcher, in which case the benefit
+/// is negligible: measure with `cargo bench --bench prefetch --features prefetch` before relying on it.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_prefetch};
+///
+/// let coeffs: Vec<f64> = (0..1000).map(|k| 1.0 / (k + 1) as f64).collect();
+///
+/// assert_eq!(horner_prefetch(0.5, &coeffs), horner(0.5, &coeffs));
+/// ```
+pub fn horner_prefetch(x: f64, coeffs: &[f64]) -> f64 {
+    let mut acc = 0.0;
+    let mut start = coeffs.len();
+    for chunk in coeffs.rchunks(LINE) {
+        start -= chunk.len();
+        if let Some(ahead) = start.checked_sub(PREFETCH_DISTANCE) {
+            prefetch(coeffs[ahead..].as_ptr());
+        }
+        acc = chunk.iter().rfold(acc, |acc, &c| acc * x + c);
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_prefetch() {
+        use super::{horner_prefetch, PREFETCH_DISTANCE};
+        use crate::horner;
+        for x in 0..32 {
+            let x = x as f64;
+            assert_eq!(horner_prefetch(x, &[]), 0.);
+            assert_eq!(horner_prefetch(x, &[1.]), 1.);
+            assert_eq!(horner_prefetch(x, &[1., 2.]), 1. + 2. * x);
+            assert_eq!(
+                horner_prefetch(x, &[1., 2., 3., 4., 5.]),
+                1. + x * (2. + x * (3. + x * (4. + x * 5.)))
+            );
+        }
+        let coeffs: Vec<f64> = (0..4 * PREFETCH_DISTANCE + 3)
+            .map(|k| (k % 17) as f64 - 8.)
+            .collect();
+        for x in [-1., -0.5, 0.25, 0.999, 1.] {
+            for n in [
+                0,
+                7,
+                8,
+                9,
+                PREFETCH_DISTANCE,
+                PREFETCH_DISTANCE + 5,
+                coeffs.len(),
+            ] {
+                assert_eq!(horner_prefetch(x, &coeffs[..n]), horner(x, &coeffs[..n]));
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+//! Evaluation of rational functions.
+
+use core::ops::{Add, Div, Mul};
+use num_traits::Zero;
+
+use crate::order::trim_zeros;
+use crate::{horner, horner_desc};
+
+/// Evaluate a rational function with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+///
+/// The coefficients of both the numerator and the denominator are listed from zeroth order
+/// to highest.
+///
+/// Returns `num(x) / den(x)`, where both polynomials are evaluated with [`horner`](fn@crate::horner).
+/// This is the form of Padé approximants and of most rational approximations of special functions.
+/// If the denominator vanishes the result is whatever the division of `T` returns,
+/// e.g. infinite or NaN for floating point numbers, or a panic for integers.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::eval_rational;
+///
+/// // (1 + 2x) / (3 + x²)
+/// assert_eq!(eval_rational(2.0, &[1.0, 2.0], &[3.0, 0.0, 1.0]), 5.0 / 7.0);
+/// ```
+#[track_caller]
+pub fn eval_rational<T>(x: T, num: &[T], den: &[T]) -> T
+where
+    T: Clone + Zero + Div<Output = T>,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    horner(x.clone(), num) / horner(x, den)
+}
+
+/// Evaluate a rational function with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// avoiding spurious overflows for large `|x|`.
+///
+/// The coefficients of both the numerator and the denominator are listed from zeroth order
+/// to highest.
+///
+/// For `|x| <= 1` this is the same as [`eval_rational`]. For `|x| > 1` both polynomials are
+/// evaluated with the coefficients reversed at `1/x`, where the powers decrease instead of
+/// growing, and the ratio is multiplied back by `xᵈ`, where `d` is the difference of the
+/// degrees (trailing zero coefficients are dropped first), one factor at a time, so that the
+/// intermediate values move monotonically towards the result. Hence the result doesn't overflow
+/// just because the numerator and the denominator do: it is finite whenever the value of the
+/// rational function is, provided that the ratio of the reversed polynomials at `1/x` is finite.
+/// The values of these polynomials are bounded by the sums of the absolute values of their
+/// coefficients, but the ratio may still overflow if the reversed denominator is tiny.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{eval_rational, eval_rational_inv};
+///
+/// // (1 + x + x²) / (1 + 2x²) tends to 1/2
+/// let (num, den) = ([1.0, 1.0, 1.0], [1.0, 0.0, 2.0]);
+/// let x: f64 = 1e200;
+///
+/// assert!(eval_rational(x, &num, &den).is_nan());
+/// assert_eq!(eval_rational_inv(x, &num, &den), 0.5);
+/// ```
+pub fn eval_rational_inv(x: f64, num: &[f64], den: &[f64]) -> f64 {
+    if x.abs() <= 1.0 {
+        return eval_rational(x, num, den);
+    }
+    let (num, den) = (trim_zeros(num), trim_zeros(den));
+    let y = 1.0 / x;
+    let mut ratio = horner_desc(y, num) / horner_desc(y, den);
+    let (n, m) = (num.len(), den.len());
+    // rather than `pow`, whose value may overflow even if the result doesn't
+    let (factor, d) = if n >= m { (x, n - m) } else { (y, m - n) };
+    for _ in 0..d {
+        ratio *= factor;
+    }
+    ratio
+}
+
+#[cfg(test)]
+mod tests {
+    /// Padé approximant [3/3] of `exp`.
+    const EXP_NUM: [f64; 4] = [1., 0.5, 0.1, 1. / 120.];
+    const EXP_DEN: [f64; 4] = [1., -0.5, 0.1, -1. / 120.];
+
+    /// Rational approximation of `atan`, `x (15 + 4x²) / (15 + 9x²)`.
+    const ATAN_NUM: [f64; 4] = [0., 15., 0., 4.];
+    const ATAN_DEN: [f64; 3] = [15., 0., 9.];
+
+    #[test]
+    fn test_eval_rational() {
+        use super::eval_rational;
+        use crate::horner;
+        for x in -16..=16 {
+            let x = x as f64 / 16.;
+            let y = eval_rational(x, &EXP_NUM, &EXP_DEN);
+            assert!((y - x.exp()).abs() <= 4e-5 * x.exp());
+            if x.abs() <= 0.5 {
+                let y = eval_rational(x, &ATAN_NUM, &ATAN_DEN);
+                assert!((y - x.atan()).abs() <= 2e-4);
+            }
+            assert_eq!(eval_rational(x, &[1., 2.], &[1.]), horner(x, &[1., 2.]));
+        }
+        assert_eq!(eval_rational(0., &EXP_NUM, &EXP_DEN), 1.);
+        assert_eq!(eval_rational(3, &[1, 2, 1], &[1, 1]), 4);
+        assert_eq!(eval_rational(2., &[1.], &[]), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_eval_rational_inv() {
+        use super::{eval_rational, eval_rational_inv};
+        for x in -64..=64 {
+            let x = x as f64 / 4.;
+            for (num, den) in [
+                (&EXP_NUM[..], &EXP_DEN[..]),
+                (&ATAN_NUM[..], &ATAN_DEN[..]),
+                (&ATAN_DEN[..], &ATAN_NUM[..]),
+            ] {
+                let expected = eval_rational(x, num, den);
+                let y = eval_rational_inv(x, num, den);
+                // the reciprocal of the approximation of atan has a pole at x = 0
+                assert!(
+                    y == expected || (y - expected).abs() <= 1e-14 * expected.abs(),
+                    "{y} is not close to {expected}"
+                );
+            }
+        }
+        // the rational approximation of atan tends to 4x / 9
+        let x = 1e300;
+        assert!(eval_rational(x, &ATAN_NUM, &ATAN_DEN).is_nan());
+        assert_eq!(eval_rational_inv(x, &ATAN_NUM, &ATAN_DEN), 4. * x / 9.);
+        assert_eq!(eval_rational_inv(-x, &ATAN_DEN, &ATAN_NUM), -9. / (4. * x));
+        assert_eq!(eval_rational_inv(x, &[], &[1.]), 0.);
+        // 1e-300 x³ at x = 1e200 is finite, though x³ is not
+        let y = eval_rational_inv(1e200, &[0., 0., 0., 1e-300], &[1.]);
+        assert!((y - 1e300).abs() <= 1e-14 * 1e300, "{y}");
+        let y = eval_rational_inv(1e200, &[1e300], &[0., 0., 0., 1.]);
+        assert!((y - 1e-300).abs() <= 1e-14 * 1e-300, "{y}");
+        // genuine overflows are still infinite
+        assert_eq!(
+            eval_rational_inv(1e200, &[0., 0., 1.], &[1.]),
+            f64::INFINITY
+        );
+        // trailing zeros don't count towards the degrees
+        let (mut num, mut den) = ([0.; 21], [0.; 21]);
+        num[..2].copy_from_slice(&[1., 1.]);
+        assert_eq!(eval_rational_inv(1e20, &num, &[1.]), 1e20);
+        assert_eq!(
+            eval_rational_inv(1e20, &num, &[1.]),
+            eval_rational(1e20, &num, &[1.])
+        );
+        den[..2].copy_from_slice(&[1., 2.]);
+        assert_eq!(eval_rational_inv(1e20, &num, &den), 0.5);
+        assert_eq!(eval_rational_inv(1e20, &[1.], &den), 0.5e-20);
+    }
+}
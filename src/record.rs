@@ -0,0 +1,81 @@
+//! Serializable records of evaluations.
+
+use serde::{Deserialize, Serialize};
+
+/// Record of the evaluation of a polynomial, for structured logging.
+///
+/// It is serialized as a struct with the fields `x`, `value`, and `degree`,
+/// e.g. to the JSON object `{"x":7.0,"value":219.0,"degree":2}`.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvalRecord {
+    /// Point of evaluation.
+    pub x: f64,
+    /// Value of the polynomial at `x`.
+    pub value: f64,
+    /// Nominal degree of the polynomial, i.e. the number of coefficients minus one
+    /// (zero if there are no coefficients), regardless of trailing zeros.
+    pub degree: usize,
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// returning a record of the evaluation.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The value is the same as the one computed by [`horner`](fn@crate::horner).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner_record, EvalRecord};
+///
+/// assert_eq!(
+///     horner_record(7.0, &[2.0, 3.0, 4.0]),
+///     EvalRecord { x: 7.0, value: 219.0, degree: 2 }
+/// );
+/// ```
+pub fn horner_record(x: f64, coeffs: &[f64]) -> EvalRecord {
+    EvalRecord {
+        x,
+        value: coeffs.iter().rfold(0.0, |acc, &c| acc * x + c),
+        degree: coeffs.len().saturating_sub(1),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_record() {
+        use super::{horner_record, EvalRecord};
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            let coeffs = [1., 2., 3., 4., 5.];
+            let record = horner_record(x, &coeffs);
+            assert_eq!(record.x, x);
+            assert_eq!(record.value, horner(x, &coeffs));
+            assert_eq!(record.degree, 4);
+        }
+        assert_eq!(
+            horner_record(2., &[]),
+            EvalRecord {
+                x: 2.,
+                value: 0.,
+                degree: 0
+            }
+        );
+        assert_eq!(horner_record(2., &[3.]).degree, 0);
+    }
+
+    #[test]
+    fn test_eval_record_serde() {
+        use super::{horner_record, EvalRecord};
+        let record = horner_record(7., &[2., 3., 4.]);
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, r#"{"x":7.0,"value":219.0,"degree":2}"#);
+        assert_eq!(serde_json::from_str::<EvalRecord>(&json).unwrap(), record);
+        let record = horner_record(0.1, &[0.2, 0.3, 0.4]);
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(serde_json::from_str::<EvalRecord>(&json).unwrap(), record);
+    }
+}
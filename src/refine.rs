@@ -0,0 +1,119 @@
+//! Iterative refinement of polynomials.
+
+use num_traits::pow;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// and perform one step of gradient descent on its coefficients.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Computes `y = p(x)` and the residual `r = y - target`, then updates the coefficients in place
+/// with `coeffs[k] -= lr * r * xᵏ`, which is a step of size `lr` along the negative gradient of
+/// the squared error `r² / 2` with respect to the coefficients. Returns `y`, the value *before*
+/// the update.
+///
+/// After the update the residual becomes `r (1 - lr ∑ x²ᵏ)`, so the iteration converges
+/// to `target` if and only if `0 < lr < 2 / ∑ x²ᵏ`, monotonically if `lr <= 1 / ∑ x²ᵏ`.
+/// With `lr = 1 / ∑ x²ᵏ` the constraint is satisfied in a single step (up to rounding),
+/// with the smallest possible change of the coefficients.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_refine};
+///
+/// let x = 2.0;
+/// let mut coeffs = [1.0, 1.0];
+///
+/// assert_eq!(horner_refine(x, &mut coeffs, 8.0, 0.1), 3.0);
+/// // the residual -5 has been scaled by 1 - 0.1 * (1 + 4)
+/// assert_eq!(coeffs, [1.5, 2.0]);
+/// assert_eq!(horner(x, &coeffs), 5.5);
+/// ```
+pub fn horner_refine(x: f64, coeffs: &mut [f64], target: f64, lr: f64) -> f64 {
+    let y = coeffs.iter().rfold(0.0, |acc, &c| acc * x + c);
+    let step = lr * (y - target);
+    let mut xk = 1.0;
+    for c in coeffs.iter_mut() {
+        *c -= step * xk;
+        xk *= x;
+    }
+    y
+}
+
+/// Update the value of a polynomial after changing one of its coefficients.
+///
+/// Given `old_value = p(x)`, returns the value at `x` of the polynomial obtained
+/// adding `delta` to the coefficient of degree `degree`, i.e. `old_value + delta * x^degree`.
+/// The power is computed by [exponentiation by squaring](https://en.wikipedia.org/wiki/Exponentiation_by_squaring),
+/// hence the update takes `O(log degree)` operations instead of the `O(n)` of a new evaluation.
+///
+/// The caller is responsible for keeping track of which coefficient changed, and by how much.
+/// Rounding errors accumulate over many consecutive updates, so it may be worth evaluating
+/// the polynomial anew from time to time.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_update};
+///
+/// let x = 3.0;
+/// let mut coeffs = [2.0, 3.0, 4.0];
+/// let y = horner(x, &coeffs);
+///
+/// coeffs[2] += 0.5;
+/// assert_eq!(horner_update(y, x, 2, 0.5), horner(x, &coeffs));
+/// ```
+pub fn horner_update(old_value: f64, x: f64, degree: usize, delta: f64) -> f64 {
+    old_value + delta * pow(x, degree)
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_refine() {
+        use super::horner_refine;
+        use crate::horner;
+        let mut coeffs = [];
+        assert_eq!(horner_refine(2., &mut coeffs, 1., 0.1), 0.);
+        for x in -8..8 {
+            let x = x as f64 / 4.;
+            // converges for lr < 2 / (1 + x² + x⁴ + x⁶)
+            let lr = 1. / (1. + x * x + x.powi(4) + x.powi(6));
+            let original = [1., -2., 3., -4.];
+            let mut coeffs = original;
+            let target = 0.5;
+            let mut residual = f64::INFINITY;
+            for _ in 0..64 {
+                let y = horner(x, &coeffs);
+                assert_eq!(horner_refine(x, &mut coeffs, target, 0.5 * lr), y);
+                let r = (horner(x, &coeffs) - target).abs();
+                assert!(r <= residual);
+                residual = r;
+            }
+            assert!(residual < 1e-12);
+            // the optimal learning rate converges in a single step
+            let mut coeffs = original;
+            horner_refine(x, &mut coeffs, target, lr);
+            assert!((horner(x, &coeffs) - target).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_horner_update() {
+        use super::horner_update;
+        use crate::horner;
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            let mut coeffs = [1., -2., 3., -4., 5., -6., 7., -8.];
+            let mut y = horner(x, &coeffs);
+            for (k, delta) in [(3, 0.5), (0, -1.), (7, 2.), (5, 0.25), (3, -0.5)] {
+                coeffs[k] += delta;
+                y = horner_update(y, x, k, delta);
+                assert_eq!(y, horner(x, &coeffs));
+            }
+        }
+        assert_eq!(horner_update(1., 0., 0, 2.), 3.);
+        assert_eq!(horner_update(1., 0., 4, 2.), 1.);
+    }
+}
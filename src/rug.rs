@@ -0,0 +1,80 @@
+//! Reference evaluation with arbitrary precision floating point numbers.
+
+use rug::Float;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// using arbitrary precision floating point numbers.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The accumulator has `prec` bits of precision, and every multiplication and addition is rounded
+/// to `prec` bits (to nearest), independently of the precisions of `x` and of the coefficients,
+/// which are used exactly. The result has precision `prec`.
+///
+/// This is meant as a high precision reference to measure the error of the `f64` evaluations.
+///
+/// # Panics
+///
+/// Panics if `prec` is out of the allowed range (see [`rug::float::prec_min`] and [`rug::float::prec_max`]).
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_rug;
+/// use rug::Float;
+///
+/// let x = Float::with_val(53, 7.0);
+/// let coeffs = [2.0, 3.0, 4.0].map(|c| Float::with_val(53, c));
+///
+/// assert_eq!(horner_rug(&x, &coeffs, 128), 2.0 + 7.0 * (3.0 + 7.0 * 4.0));
+/// assert_eq!(horner_rug(&x, &coeffs, 128).prec(), 128);
+/// ```
+pub fn horner_rug(x: &Float, coeffs: &[Float], prec: u32) -> Float {
+    coeffs
+        .iter()
+        .rfold(Float::new(prec), |acc: Float, c: &Float| acc * x + c)
+}
+
+#[cfg(test)]
+mod tests {
+    use rug::Float;
+
+    #[test]
+    fn test_horner_rug() {
+        use super::horner_rug;
+        use crate::horner;
+        for x in -32..32 {
+            let x = x as f64;
+            let xf = Float::with_val(53, x);
+            assert_eq!(horner_rug(&xf, &[], 64), 0.);
+            let coeffs = [1., 2., 3., 4., 5.];
+            let coeffs_f = coeffs.map(|c| Float::with_val(53, c));
+            assert_eq!(horner_rug(&xf, &coeffs_f, 64), horner(x, &coeffs));
+        }
+        // 1 + x + ... + x¹⁰⁰ at x = 2: exact with 101 bits, not with 100
+        let x = Float::with_val(2, 2);
+        let coeffs = vec![Float::with_val(1, 1); 101];
+        let exact = (Float::with_val(128, 1) << 101) - 1;
+        assert_eq!(horner_rug(&x, &coeffs, 101), exact);
+        assert_ne!(horner_rug(&x, &coeffs, 100), exact);
+    }
+
+    #[test]
+    fn test_horner_rug_accuracy() {
+        use super::horner_rug;
+        use crate::compensated::horner_compensated;
+        use crate::horner;
+        // (x - 1)⁵ close to its root
+        let coeffs = [-1., 5., -10., 10., -5., 1.];
+        let coeffs_f = coeffs.map(|c| Float::with_val(53, c));
+        for k in 1..32 {
+            let x = 1. + k as f64 / 3000.;
+            let reference = horner_rug(&Float::with_val(53, x), &coeffs_f, 256);
+            let error = |y: f64| (Float::with_val(256, y) - &reference).abs();
+            let plain = error(horner(x, &coeffs));
+            let compensated = error(horner_compensated(x, &coeffs));
+            assert!(compensated < plain);
+            assert!(compensated <= reference.clone().abs() * 1e-12);
+        }
+    }
+}
@@ -0,0 +1,160 @@
+//! Evaluation with extended exponent range.
+
+/// Splits a finite nonzero `v` into `(m, e)` with `v = m * 2ᵉ` and `0.5 <= |m| < 1`.
+///
+/// Zero and non-finite values are returned unchanged, with exponent `0`.
+fn frexp(v: f64) -> (f64, i32) {
+    if v == 0.0 || !v.is_finite() {
+        return (v, 0);
+    }
+    let bits = v.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+    if exp == 0 {
+        // subnormal: scale it into the normal range first
+        let (m, e) = frexp(v * pow2(54));
+        return (m, e - 54);
+    }
+    let m = f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52));
+    (m, exp - 1022)
+}
+
+/// Computes `2ᵏ` for `-1022 <= k <= 1023`.
+fn pow2(k: i32) -> f64 {
+    debug_assert!((-1022..=1023).contains(&k));
+    f64::from_bits(((k + 1023) as u64) << 52)
+}
+
+/// Adds `a * 2ᵃᵉ` and `b * 2ᵇᵉ`, where `0.5 <= |a|, |b| < 1`, returning the normalized sum.
+fn add_scaled((a, ae): (f64, i32), (b, be): (f64, i32)) -> (f64, i32) {
+    let ((big, e), (small, se)) = if ae >= be {
+        ((a, ae), (b, be))
+    } else {
+        ((b, be), (a, ae))
+    };
+    // below this shift the small term is less than a quarter of an ulp of the big one,
+    // so it doesn't affect the rounded sum
+    let sum = match e - se {
+        d if d > 60 => big,
+        d => big + small * pow2(-d),
+    };
+    let (m, k) = frexp(sum);
+    (m, e + k)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// keeping the exponent of the accumulator separate to extend the dynamic range.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns `(m, e)` representing the value `m * 2ᵉ`, where the mantissa satisfies
+/// `0.5 <= |m| < 1`, like the C function `frexp`. The point `x` is split in the same way once,
+/// and after every step the accumulator is renormalized, so the intermediate and final values
+/// never overflow or underflow even when they are far outside the range of `f64`, e.g. `10⁴⁰⁰`
+/// is returned as `(0.853…, 1329)`, and a subnormal `x` doesn't lose precision.
+/// The precision is the same as for [`horner`](fn@crate::horner).
+///
+/// A zero value is returned as `(0.0, 0)`, and a non-finite value (because `x` or some
+/// coefficient is infinite or NaN) as `(m, 0)` with `m` infinite or NaN.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{horner, horner_logspace};
+///
+/// let x = 2.0_f64.powi(600);
+/// let coeffs = [3.0, 0.0, 5.0];
+///
+/// // 5 * 2¹²⁰⁰ + 3 overflows
+/// assert_eq!(horner(x, &coeffs), f64::INFINITY);
+/// // but 5 * 2¹²⁰⁰ = 0.625 * 2¹²⁰³
+/// assert_eq!(horner_logspace(x, &coeffs), (0.625, 1203));
+///
+/// assert_eq!(horner_logspace(3.0, &[1.0, 2.0]), (0.875, 3));
+/// ```
+pub fn horner_logspace(x: f64, coeffs: &[f64]) -> (f64, i32) {
+    let (xm, xe) = frexp(x);
+    let (m, e) = coeffs.iter().rfold((0.0, 0), |(m, e), &c| {
+        let (t, k) = frexp(m * xm);
+        let (c, ce) = frexp(c);
+        if t == 0.0 {
+            (c, ce)
+        } else if c == 0.0 {
+            (t, e + xe + k)
+        } else {
+            add_scaled((t, e + xe + k), (c, ce))
+        }
+    });
+    if m == 0.0 || !m.is_finite() {
+        (m, 0)
+    } else {
+        (m, e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_frexp() {
+        use super::{frexp, pow2};
+        assert_eq!(frexp(1.), (0.5, 1));
+        assert_eq!(frexp(-3.), (-0.75, 2));
+        assert_eq!(frexp(0.375), (0.75, -1));
+        assert_eq!(frexp(f64::MAX), (f64::MAX / pow2(1023) / 2., 1024));
+        assert_eq!(frexp(f64::MIN_POSITIVE), (0.5, -1021));
+        assert_eq!(frexp(f64::from_bits(1)), (0.5, -1073));
+        assert_eq!(frexp(0.), (0., 0));
+        assert_eq!(frexp(f64::NEG_INFINITY), (f64::NEG_INFINITY, 0));
+    }
+
+    #[test]
+    fn test_horner_logspace() {
+        use super::{frexp, horner_logspace, pow2};
+        use crate::horner;
+        // in the range of `f64` the results agree with plain Horner
+        for x in -32..32 {
+            let x = x as f64 / 4.;
+            let coeffs = [1., -2., 3., -4., 5.];
+            let (m, e) = horner_logspace(x, &coeffs);
+            assert!(m == 0. || (0.5..1.).contains(&m.abs()));
+            let scale = if e >= 0 { pow2(e) } else { 1. / pow2(-e) };
+            assert_eq!(m * scale, horner(x, &coeffs));
+        }
+        assert_eq!(horner_logspace(2., &[]), (0., 0));
+        assert_eq!(horner_logspace(2., &[1., -1., 0.5]), (0.5, 1));
+        assert_eq!(horner_logspace(2., &[-2., 1.]), (0., 0));
+        // overflow: x¹⁰⁰ with x = 2¹⁰⁰⁰
+        let mut coeffs = [0.; 101];
+        coeffs[100] = -1.;
+        coeffs[0] = 1.;
+        assert_eq!(horner(pow2(1000), &coeffs), f64::NEG_INFINITY);
+        assert_eq!(horner_logspace(pow2(1000), &coeffs), (-0.5, 100_001));
+        // underflow: 2⁻¹²⁰⁰ + 2⁻¹²⁵⁰ has its trailing bit outside of the subnormal range
+        let x = 1. / pow2(600);
+        assert_eq!(horner(x, &[0., 0., 1.]), 0.);
+        assert_eq!(horner_logspace(x, &[0., 0., 1.]), (0.5, -1199));
+        assert_eq!(
+            horner_logspace(x, &[0., pow2(-650), 1.]),
+            (0.5 + pow2(-51), -1199)
+        );
+        // subnormal points are normalized too
+        let x = f64::from_bits(1);
+        assert_eq!(horner(x, &[0., 1.]), x);
+        assert_eq!(horner_logspace(x, &[0., 1.]), (0.5, -1073));
+        assert_eq!(horner_logspace(x, &[0., 0., 1.]), (0.5, -2147));
+        let x = 2.9e-311;
+        let (m, e) = frexp((x * pow2(600)) * (x * pow2(600)));
+        assert_eq!(horner_logspace(x, &[0., 0., 1.]), (m, e - 1200));
+        // the scaled terms are normalized back together
+        let (m, e) = horner_logspace(1e200, &[1., 1., 1.]);
+        let log10 = (m.log2() + e as f64) / 10f64.log2();
+        assert!((log10 - 400.).abs() < 1e-12);
+        // non-finite values
+        assert_eq!(
+            horner_logspace(2., &[f64::INFINITY, 1.]),
+            (f64::INFINITY, 0)
+        );
+        assert!(horner_logspace(f64::INFINITY, &[1., 1.]).0.is_nan());
+        assert!(horner_logspace(f64::NAN, &[1., 1.]).0.is_nan());
+        assert!(horner_logspace(1., &[f64::NAN]).0.is_nan());
+    }
+}
@@ -0,0 +1,133 @@
+//! Sign determination of polynomials.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Runs [`horner_sign_only`], returning also the number of Horner steps performed.
+fn horner_sign_steps(x: f64, coeffs: &[f64]) -> (Ordering, usize) {
+    let n = coeffs.len();
+    // Forward pass: tails[k] = ∑ⱼ₌₀..ₖ |cⱼ| |x|ʲ⁻ᵏ bounds the contribution of the terms
+    // of degree less than `k`, relative to the scale |x|ᵏ of the partial Horner value.
+    let ax = x.abs();
+    let mut tails = Vec::with_capacity(n);
+    let mut tail = 0.0;
+    for &c in coeffs {
+        tails.push(tail);
+        tail = (tail + c.abs()) / ax;
+    }
+    // allow for the rounding errors in the computation of the bounds
+    let margin = 1.0 + 4.0 * (n as f64 + 1.0) * f64::EPSILON;
+    let u = f64::EPSILON / 2.0;
+    let (mut y, mut mu) = (0.0, 0.0);
+    for (k, &c) in coeffs.iter().enumerate().rev() {
+        let first = k + 1 == n;
+        y = y * x + c;
+        // running error bound of the partial value, see Higham, Algorithm 5.1
+        mu = if first {
+            y.abs() / 2.0
+        } else {
+            mu * ax + y.abs()
+        };
+        let err = u * (2.0 * mu - y.abs());
+        if y.abs() > margin * (tails[k] + err) {
+            let sign = if y > 0.0 {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+            // `x` is negative and the remaining degree `k` is odd
+            let sign = if x < 0.0 && k % 2 == 1 {
+                sign.reverse()
+            } else {
+                sign
+            };
+            return (sign, n - k);
+        }
+    }
+    (y.partial_cmp(&0.0).unwrap_or(Ordering::Equal), n)
+}
+
+/// Compute the sign of a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// stopping as soon as the remaining terms can't change it.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Returns how `p(x)` compares with zero. After processing the coefficients of degree `k` and
+/// higher, Horner's method holds the value `qₖ` such that `p(x) = qₖ xᵏ + ∑ⱼ₌₀..ₖ cⱼ xʲ`; as soon
+/// as `|qₖ| |x|ᵏ` exceeds the bound `∑ⱼ₌₀..ₖ |cⱼ| |x|ʲ` on the remaining terms the sign of `p(x)`
+/// is determined, and the evaluation stops. The rounding errors of `qₖ` are taken into account
+/// with a running error bound, and the bounds are slightly inflated to account for their own
+/// rounding, so that the early exit is conservative.
+///
+/// This requires a forward pass over the coefficients to compute the tail bounds, stored in a
+/// temporary buffer. It pays off for high degree polynomials dominated by the leading terms,
+/// i.e. away from their roots when `|x|` is large.
+///
+/// If the sign is not determined early, the sign of the computed value is returned, which near
+/// the roots may be wrong because of rounding, just like for [`horner`](fn@crate::horner).
+/// A NaN value returns [`Ordering::Equal`].
+///
+/// # Examples
+///
+/// ```
+/// use core::cmp::Ordering;
+/// use polyeval::horner_sign_only;
+///
+/// // p(x) = x² - 4 = (x - 2)(x + 2)
+/// let coeffs = [-4.0, 0.0, 1.0];
+///
+/// assert_eq!(horner_sign_only(3.0, &coeffs), Ordering::Greater);
+/// assert_eq!(horner_sign_only(-1.0, &coeffs), Ordering::Less);
+/// assert_eq!(horner_sign_only(-2.0, &coeffs), Ordering::Equal);
+/// ```
+pub fn horner_sign_only(x: f64, coeffs: &[f64]) -> Ordering {
+    horner_sign_steps(x, coeffs).0
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_sign_only() {
+        use super::horner_sign_only;
+        use crate::horner;
+        use core::cmp::Ordering;
+        assert_eq!(horner_sign_only(2., &[]), Ordering::Equal);
+        assert_eq!(horner_sign_only(2., &[-3.]), Ordering::Less);
+        assert_eq!(horner_sign_only(0., &[1., -5., 7.]), Ordering::Greater);
+        assert_eq!(horner_sign_only(0., &[0., -5., 7.]), Ordering::Equal);
+        assert_eq!(horner_sign_only(f64::NAN, &[1., 1.]), Ordering::Equal);
+        // p(x) = (x + 3)(x + 1)(x - 1/2)(x - 2)(x - 4)
+        let coeffs = [-12., 17., 12.5, -12.5, -2.5, 1.];
+        for k in -64..=64 {
+            let x = k as f64 / 8.;
+            let expected = horner(x, &coeffs).partial_cmp(&0.).unwrap();
+            assert_eq!(horner_sign_only(x, &coeffs), expected, "at {x}");
+        }
+        for k in -64..=64 {
+            let x = k as f64 * 1.75;
+            let coeffs: [f64; 8] = core::array::from_fn(|j| ((j * 5 + 3) % 7) as f64 - 3.);
+            let expected = horner(x, &coeffs).partial_cmp(&0.).unwrap();
+            assert_eq!(horner_sign_only(x, &coeffs), expected, "at {x}");
+        }
+    }
+
+    #[test]
+    fn test_horner_sign_only_short_circuits() {
+        use super::horner_sign_steps;
+        use core::cmp::Ordering;
+        // the leading term dominates everything else
+        let mut coeffs = [1.; 32];
+        coeffs[31] = -1e6;
+        assert_eq!(horner_sign_steps(4., &coeffs), (Ordering::Less, 1));
+        assert_eq!(horner_sign_steps(-4., &coeffs), (Ordering::Greater, 1));
+        // the first two terms cancel, then the third one dominates
+        let mut coeffs = [1.; 32];
+        coeffs[31] = 1.;
+        coeffs[30] = -4.;
+        coeffs[29] = -1e6;
+        assert_eq!(horner_sign_steps(4., &coeffs), (Ordering::Less, 3));
+        // close to a root every coefficient is needed
+        assert_eq!(horner_sign_steps(1.9, &[-4., 0., 1.]), (Ordering::Less, 3));
+        assert_eq!(horner_sign_steps(2., &[-4., 0., 1.]), (Ordering::Equal, 3));
+    }
+}
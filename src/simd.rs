@@ -0,0 +1,312 @@
+//! Lane-wise evaluation with [portable SIMD](core::simd).
+
+use core::simd::num::SimdFloat;
+use core::simd::{Mask, Select, Simd};
+use std::simd::StdFloat;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// on the active lanes of a SIMD vector.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The lanes of `x` which are set in `mask` are evaluated lane-wise using "fused multiply-add"
+/// instructions; the other lanes of the result are taken from `default`.
+///
+/// This is meant for the partial-width tail of an array of points, which would otherwise require
+/// a scalar loop. Masked-off lanes may still be computed, but their value is ignored:
+/// they are evaluated at zero, so that garbage in the inactive lanes of `x` cannot raise
+/// spurious floating point exceptions.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::{Mask, Simd};
+/// use polyeval::horner_masked;
+///
+/// let x = Simd::from_array([1.0, 2.0, f64::NAN, f64::NAN]);
+/// let mask = Mask::from_array([true, true, false, false]);
+/// let default = Simd::splat(-1.0);
+///
+/// assert_eq!(
+///     horner_masked(x, mask, &[2.0, 3.0, 4.0], default).to_array(),
+///     [9.0, 24.0, -1.0, -1.0]
+/// );
+/// ```
+pub fn horner_masked<const L: usize>(
+    x: Simd<f64, L>,
+    mask: Mask<i64, L>,
+    coeffs: &[f64],
+    default: Simd<f64, L>,
+) -> Simd<f64, L> {
+    let x = mask.select(x, Simd::splat(0.0));
+    let y = coeffs
+        .iter()
+        .rfold(Simd::splat(0.0), |acc, &c| acc.mul_add(x, Simd::splat(c)));
+    mask.select(y, default)
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at the 8 lanes of a SIMD vector of `f32`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Every lane is evaluated independently using "fused multiply-add" instructions, hence lane `j`
+/// of the result is the same as [`horner_fma`](fn@crate::horner_fma) at `x[j]`. On targets
+/// without hardware support for them the fused operations are emulated, which is slower but
+/// gives the same results.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::Simd;
+/// use polyeval::horner_simd_f32x8;
+///
+/// let x = Simd::from_array([0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]);
+///
+/// assert_eq!(
+///     horner_simd_f32x8(x, &[2.0, 3.0, 4.0]).to_array(),
+///     [2.0, 9.0, 24.0, 47.0, 78.0, 117.0, 164.0, 219.0]
+/// );
+/// ```
+pub fn horner_simd_f32x8(x: Simd<f32, 8>, coeffs: &[f32]) -> Simd<f32, 8> {
+    coeffs
+        .iter()
+        .rfold(Simd::splat(0.0), |acc, &c| acc.mul_add(x, Simd::splat(c)))
+}
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at the 4 lanes of a SIMD vector of `f64`.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Same as [`horner_simd_f32x8`], in double precision.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(portable_simd)]
+/// use core::simd::Simd;
+/// use polyeval::horner_simd_f64x4;
+///
+/// let x = Simd::from_array([0.0, 1.0, 2.0, 7.0]);
+///
+/// assert_eq!(
+///     horner_simd_f64x4(x, &[2.0, 3.0, 4.0]).to_array(),
+///     [2.0, 9.0, 24.0, 219.0]
+/// );
+/// ```
+pub fn horner_simd_f64x4(x: Simd<f64, 4>, coeffs: &[f64]) -> Simd<f64, 4> {
+    coeffs
+        .iter()
+        .rfold(Simd::splat(0.0), |acc, &c| acc.mul_add(x, Simd::splat(c)))
+}
+
+/// Number of coefficients gathered at once by [`horner_gather`].
+const GATHER_LANES: usize = 4;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// gathering the coefficients from scattered positions of a buffer.
+///
+/// The coefficients are `buffer[offsets[0]], buffer[offsets[1]], ...`,
+/// listed from zeroth order to highest.
+///
+/// The coefficients are loaded with SIMD gathers, a few at a time, which avoids a compacting copy
+/// when they are stored interleaved with other data (e.g. in an array of structs).
+///
+/// # Panics
+///
+/// Panics if any offset is out of bounds for `buffer`, before performing any computation.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_gather;
+///
+/// let x = 7.0;
+/// // coefficients interleaved with other data
+/// let buffer = [2.0, -1.0, 3.0, -1.0, 4.0, -1.0];
+///
+/// assert_eq!(
+///     horner_gather(x, &buffer, &[0, 2, 4]),
+///     2.0 + x * (3.0 + x * 4.0)
+/// );
+/// ```
+pub fn horner_gather(x: f64, buffer: &[f64], offsets: &[usize]) -> f64 {
+    if let Some(&offset) = offsets.iter().find(|&&offset| offset >= buffer.len()) {
+        panic!(
+            "offset out of bounds: the length is {} but the offset is {offset}",
+            buffer.len()
+        );
+    }
+    offsets.rchunks(GATHER_LANES).fold(0.0, |acc, chunk| {
+        if let Ok(chunk) = <[usize; GATHER_LANES]>::try_from(chunk) {
+            Simd::<f64, GATHER_LANES>::gather_or_default(buffer, Simd::from_array(chunk))
+                .to_array()
+                .iter()
+                .rfold(acc, |acc, &c| acc * x + c)
+        } else {
+            chunk
+                .iter()
+                .rfold(acc, |acc, &offset| acc * x + buffer[offset])
+        }
+    })
+}
+
+/// Evaluate a single polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// striping the coefficients across the lanes of a SIMD vector.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// Lane `j` accumulates the coefficients `cⱼ, cⱼ₊ₗ, cⱼ₊₂ₗ, ...` with Horner's method in `y = xᴸ`,
+/// using "fused multiply-add" instructions, and at the end the lanes are combined
+/// with the horizontal sum `∑ⱼ xʲ accⱼ`. This uses all the lanes for a single high-degree polynomial.
+///
+/// The operations are reassociated with respect to [`horner`](fn@crate::horner),
+/// hence the result may differ from it slightly due to rounding.
+///
+/// The number of lanes `L` must be positive, otherwise the function fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_simd_single;
+///
+/// let x = 7.0;
+///
+/// assert_eq!(
+///     horner_simd_single::<4>(x, &[2.0, 3.0, 4.0, 5.0, 6.0, 7.0]),
+///     2.0 + x * (3.0 + x * (4.0 + x * (5.0 + x * (6.0 + x * 7.0))))
+/// );
+/// ```
+pub fn horner_simd_single<const L: usize>(x: f64, coeffs: &[f64]) -> f64 {
+    const { assert!(L > 0, "the number of lanes must be positive") };
+    let mut powers = [1.0; L];
+    for j in 1..L {
+        powers[j] = powers[j - 1] * x;
+    }
+    let y = Simd::splat(powers[L - 1] * x);
+    let acc = coeffs
+        .chunks(L)
+        .rfold(Simd::<f64, L>::splat(0.0), |acc, chunk| {
+            acc.mul_add(y, Simd::load_or_default(chunk))
+        });
+    (acc * Simd::from_array(powers)).reduce_sum()
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_masked() {
+        use super::horner_masked;
+        use crate::horner;
+        use core::simd::{Mask, Simd};
+
+        let coeffs = [1., 2., 3., 4., 5.];
+        let default = Simd::splat(f64::INFINITY);
+        for x in 0..32 {
+            let x = x as f64;
+            let xs = Simd::from_array([x, x + 1., -x, 2. * x]);
+            for bits in 0..16u8 {
+                let mask = Mask::from_bitmask(bits as u64);
+                let y = horner_masked(xs, mask, &coeffs, default);
+                for lane in 0..4 {
+                    if mask.test(lane) {
+                        assert_eq!(y[lane], horner(xs[lane], &coeffs));
+                    } else {
+                        assert_eq!(y[lane], f64::INFINITY);
+                    }
+                }
+            }
+        }
+        let mask = Mask::splat(true);
+        assert_eq!(
+            horner_masked(Simd::<f64, 8>::splat(3.), mask, &[], Simd::splat(1.)),
+            Simd::splat(0.)
+        );
+    }
+
+    #[test]
+    fn test_horner_simd_f32x8() {
+        use super::horner_simd_f32x8;
+        use crate::{horner, horner_fma};
+        use core::simd::Simd;
+
+        let coeffs = [1., -0.5, 0.25, 3., -2., 0.125];
+        for k in -16..16 {
+            let xs = Simd::from_array(core::array::from_fn(|j| (k * 8 + j as i32) as f32 / 32.));
+            assert_eq!(horner_simd_f32x8(xs, &[]), Simd::splat(0.));
+            assert_eq!(horner_simd_f32x8(xs, &[3.]), Simd::splat(3.));
+            let y = horner_simd_f32x8(xs, &coeffs);
+            for lane in 0..8 {
+                assert_eq!(y[lane], horner_fma(xs[lane], &coeffs));
+                let plain = horner(xs[lane], &coeffs);
+                assert!((y[lane] - plain).abs() <= 1e-5 * plain.abs().max(1.));
+            }
+        }
+    }
+
+    #[test]
+    fn test_horner_simd_f64x4() {
+        use super::horner_simd_f64x4;
+        use crate::{horner, horner_fma};
+        use core::simd::Simd;
+
+        let coeffs = [1., -0.5, 0.25, 3., -2., 0.125];
+        for k in -16..16 {
+            let xs = Simd::from_array(core::array::from_fn(|j| (k * 4 + j as i32) as f64 / 16.));
+            assert_eq!(horner_simd_f64x4(xs, &[]), Simd::splat(0.));
+            assert_eq!(horner_simd_f64x4(xs, &[3.]), Simd::splat(3.));
+            let y = horner_simd_f64x4(xs, &coeffs);
+            for lane in 0..4 {
+                assert_eq!(y[lane], horner_fma(xs[lane], &coeffs));
+                let plain = horner(xs[lane], &coeffs);
+                assert!((y[lane] - plain).abs() <= 1e-12 * plain.abs().max(1.));
+            }
+        }
+    }
+
+    #[test]
+    fn test_horner_gather() {
+        use super::horner_gather;
+        use crate::horner;
+        let buffer: Vec<f64> = (0..64).map(|k| (k % 13) as f64 - 6.).collect();
+        let offsets: Vec<usize> = (0..20).map(|k| (k * 7 + 3) % buffer.len()).collect();
+        let compacted: Vec<f64> = offsets.iter().map(|&k| buffer[k]).collect();
+        for x in -16..16 {
+            let x = x as f64 / 4.;
+            for n in 0..=offsets.len() {
+                assert_eq!(
+                    horner_gather(x, &buffer, &offsets[..n]),
+                    horner(x, &compacted[..n])
+                );
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "offset out of bounds")]
+    fn test_horner_gather_out_of_bounds() {
+        use super::horner_gather;
+        horner_gather(2., &[1., 2., 3.], &[0, 1, 2, 0, 1, 3]);
+    }
+
+    #[test]
+    fn test_horner_simd_single() {
+        use super::horner_simd_single;
+        use crate::horner;
+        let coeffs: Vec<f64> = (0..37).map(|k| ((k * 5) % 7) as f64 - 3.).collect();
+        // exactly representable partial results
+        for x in [-2., -1., -0.5, 0., 0.5, 1., 2.] {
+            for n in 0..=coeffs.len() {
+                let y = horner(x, &coeffs[..n]);
+                assert_eq!(horner_simd_single::<1>(x, &coeffs[..n]), y);
+                assert_eq!(horner_simd_single::<2>(x, &coeffs[..n]), y);
+                assert_eq!(horner_simd_single::<4>(x, &coeffs[..n]), y);
+                assert_eq!(horner_simd_single::<8>(x, &coeffs[..n]), y);
+                assert_eq!(horner_simd_single::<3>(x, &coeffs[..n]), y);
+            }
+        }
+    }
+}
@@ -0,0 +1,271 @@
+//! Evaluation with independent accumulators, for instruction-level parallelism.
+
+use core::mem;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// splitting it into its even and odd parts.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The polynomial is written as `E(x²) + x O(x²)`, where `E` and `O` have the coefficients
+/// of even and odd order, respectively, and both of them are evaluated with Horner's method in
+/// `x²`. Their dependency chains are independent, hence a superscalar CPU can execute them in
+/// parallel, roughly halving the latency for long polynomials.
+///
+/// For integers the result is the same as [`horner`](fn@crate::horner); for floating point
+/// numbers the operations are reassociated, hence the result may differ slightly due to rounding.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_even_odd;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_even_odd(x, &[]), 0);
+/// assert_eq!(
+///     horner_even_odd(x, &[2, 3, 4, 5, 6]),
+///     2 + x * (3 + x * (4 + x * (5 + x * 6)))
+/// );
+/// ```
+#[track_caller]
+pub fn horner_even_odd<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    // computed only if needed, so that it doesn't overflow when `horner` doesn't
+    let x2 = if coeffs.len() > 2 {
+        x.clone() * &x
+    } else {
+        T::zero()
+    };
+    let (mut even, mut odd) = (T::zero(), T::zero());
+    for c in coeffs.iter().step_by(2).rev() {
+        even = even * &x2 + c;
+    }
+    for c in coeffs.iter().skip(1).step_by(2).rev() {
+        odd = odd * &x2 + c;
+    }
+    even + &(odd * &x)
+}
+
+/// Evaluate a polynomial with a second-order [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using two accumulators.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The coefficients are processed in pairs `(c₂ₖ, c₂ₖ₊₁)` from the highest order down, updating
+/// two accumulators in `x²` in the same loop, which are merged at the end as `a₀ + x a₁`.
+/// The two updates of every iteration are independent, which breaks the serial dependency of
+/// classic Horner roughly in half. This computes the same as [`horner_even_odd`], but in a single
+/// pass over the coefficients.
+///
+/// For integers the result is the same as [`horner`](fn@crate::horner); for floating point
+/// numbers the operations are reassociated, hence the result may differ slightly due to rounding.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_2way;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_2way(x, &[]), 0);
+/// assert_eq!(
+///     horner_2way(x, &[2, 3, 4, 5, 6]),
+///     2 + x * (3 + x * (4 + x * (5 + x * 6)))
+/// );
+/// ```
+#[track_caller]
+pub fn horner_2way<T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    // computed only if needed, so that it doesn't overflow when `horner` doesn't
+    let x2 = if coeffs.len() > 2 {
+        x.clone() * &x
+    } else {
+        T::zero()
+    };
+    let (mut a0, mut a1) = (T::zero(), T::zero());
+    for pair in coeffs.chunks(2).rev() {
+        a0 = a0 * &x2 + &pair[0];
+        if let Some(c) = pair.get(1) {
+            a1 = a1 * &x2 + c;
+        }
+    }
+    a0 + &(a1 * &x)
+}
+
+/// Evaluate a polynomial with a `K`-th order [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method),
+/// using `K` accumulators.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The accumulator `aⱼ` evaluates the polynomial with the coefficients `cⱼ, cⱼ₊ₖ, cⱼ₊₂ₖ, ...`
+/// in `xᴷ`, and they are all updated in the same loop, from the highest order down.
+/// At the end they are combined with Horner's method in `x`, as `a₀ + x a₁ + ... + xᴷ⁻¹ aₖ₋₁`.
+/// The `K` updates of every iteration are independent, so `K` can be chosen to match the ratio
+/// between the latency and the throughput of the multiply-add instructions of the CPU.
+/// With `K = 2` this is the same as [`horner_2way`], and with `K = 1` the same as plain Horner.
+///
+/// For integers the result is the same as [`horner`](fn@crate::horner); for floating point
+/// numbers the operations are reassociated, hence the result may differ slightly due to rounding.
+///
+/// The number of accumulators `K` must be positive, otherwise the function fails to compile.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::horner_kway;
+///
+/// let x = 7;
+///
+/// assert_eq!(horner_kway::<3, _>(x, &[]), 0);
+/// assert_eq!(
+///     horner_kway::<3, _>(x, &[2, 3, 4, 5, 6]),
+///     2 + x * (3 + x * (4 + x * (5 + x * 6)))
+/// );
+/// ```
+#[track_caller]
+pub fn horner_kway<const K: usize, T>(x: T, coeffs: &[T]) -> T
+where
+    T: Clone + Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    const { assert!(K > 0, "the number of accumulators must be positive") };
+    // computed only if there is more than one block, so that it doesn't overflow when `horner` doesn't
+    let mut xk = T::zero();
+    if coeffs.len() > K {
+        xk = x.clone();
+        for _ in 1..K {
+            xk = xk * &x;
+        }
+    }
+    let mut accs: [T; K] = core::array::from_fn(|_| T::zero());
+    for chunk in coeffs.chunks(K).rev() {
+        for (acc, c) in accs.iter_mut().zip(chunk) {
+            *acc = mem::replace(acc, T::zero()) * &xk + c;
+        }
+    }
+    let mut acc = T::zero();
+    for a in accs.iter().rev() {
+        acc = acc * &x + a;
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_kway() {
+        use super::{horner_2way, horner_kway};
+        use crate::horner;
+
+        fn check<const K: usize>() {
+            let coeffs: [i64; 20] = core::array::from_fn(|k| (k as i64 % 5) - 2);
+            for x in -6..6 {
+                for n in 0..=coeffs.len() {
+                    assert_eq!(
+                        horner_kway::<K, _>(x, &coeffs[..n]),
+                        horner(x, &coeffs[..n])
+                    );
+                }
+            }
+            let coeffs: [f64; 20] = core::array::from_fn(|k| 1. / (k + 1) as f64);
+            for x in -8..8 {
+                let x = x as f64 / 8.;
+                for n in 0..=coeffs.len() {
+                    let expected = horner(x, &coeffs[..n]);
+                    let y = horner_kway::<K, _>(x, &coeffs[..n]);
+                    assert!((y - expected).abs() <= 1e-14 * expected.abs().max(1.));
+                }
+            }
+        }
+
+        check::<1>();
+        check::<2>();
+        check::<3>();
+        check::<4>();
+        check::<8>();
+        let coeffs = [0.5, -0.25, 1. / 3., 0.125, -2., 1.];
+        for x in -8..8 {
+            let x = x as f64 / 8.;
+            assert_eq!(horner_kway::<1, _>(x, &coeffs), horner(x, &coeffs));
+            assert_eq!(horner_kway::<2, _>(x, &coeffs), horner_2way(x, &coeffs));
+        }
+        // x⁸ overflows, but it isn't needed
+        assert_eq!(horner_kway::<8, _>(100i32, &[1, 2]), 201);
+        assert_eq!(horner_kway::<8, _>(100i32, &[1, 2, 0, 0, 0, 0, 0, 0]), 201);
+        let x = i32::MAX / 2;
+        assert_eq!(horner_kway::<4, _>(x, &[1, 2]), i32::MAX);
+        assert_eq!(horner_kway::<4, _>(x, &[1, 2]), horner(x, &[1, 2]));
+        // x³ = 1290³ is close to the bound, with two blocks
+        assert_eq!(
+            horner_kway::<3, _>(1290i32, &[5, 0, 0, 1]),
+            1290 * 1290 * 1290 + 5
+        );
+    }
+
+    #[test]
+    fn test_horner_2way() {
+        use super::{horner_2way, horner_even_odd};
+        use crate::horner;
+        let coeffs: [i64; 16] = core::array::from_fn(|k| (k as i64 % 5) - 2);
+        for x in -8..8 {
+            for n in 0..=coeffs.len() {
+                assert_eq!(horner_2way(x, &coeffs[..n]), horner(x, &coeffs[..n]));
+            }
+        }
+        let coeffs: [f64; 16] = core::array::from_fn(|k| 1. / (k + 1) as f64);
+        for x in -8..8 {
+            let x = x as f64 / 8.;
+            for n in 0..=coeffs.len() {
+                let expected = horner(x, &coeffs[..n]);
+                let y = horner_2way(x, &coeffs[..n]);
+                assert!((y - expected).abs() <= 1e-14 * expected.abs().max(1.));
+                assert_eq!(y, horner_even_odd(x, &coeffs[..n]));
+            }
+        }
+        // x² overflows, but it isn't needed
+        assert_eq!(horner_2way(100_000i32, &[1, 2]), 200_001);
+        let x = i32::MAX / 2;
+        assert_eq!(horner_2way(x, &[1, 2]), i32::MAX);
+        assert_eq!(horner_2way(-x - 1, &[0, 2]), i32::MIN);
+        assert_eq!(horner_2way(46_340i32, &[0, 0, 1]), 46_340 * 46_340);
+    }
+
+    #[test]
+    fn test_horner_even_odd() {
+        use super::horner_even_odd;
+        use crate::horner;
+        let coeffs: [i64; 16] = core::array::from_fn(|k| (k as i64 % 5) - 2);
+        for x in -8..8 {
+            for n in 0..=coeffs.len() {
+                assert_eq!(horner_even_odd(x, &coeffs[..n]), horner(x, &coeffs[..n]));
+            }
+        }
+        let coeffs: [f64; 16] = core::array::from_fn(|k| 1. / (k + 1) as f64);
+        for x in -8..8 {
+            let x = x as f64 / 8.;
+            for n in 0..=coeffs.len() {
+                let expected = horner(x, &coeffs[..n]);
+                let y = horner_even_odd(x, &coeffs[..n]);
+                assert!((y - expected).abs() <= 1e-14 * expected.abs().max(1.));
+            }
+        }
+        // x² overflows, but it isn't needed
+        assert_eq!(horner_even_odd(100_000i32, &[7]), 7);
+        let x = i32::MAX / 2;
+        assert_eq!(horner_even_odd(x, &[1, 2]), i32::MAX);
+        assert_eq!(horner_even_odd(x, &[1, 2]), horner(x, &[1, 2]));
+        assert_eq!(horner_even_odd(46_340i32, &[0, 0, 1]), 46_340 * 46_340);
+    }
+}
@@ -0,0 +1,53 @@
+//! Evaluation at points produced by an asynchronous stream.
+
+use alloc::vec::Vec;
+use futures::stream::{Stream, StreamExt};
+
+/// Evaluate a polynomial with [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method)
+/// at every point produced by a stream.
+///
+/// The coefficients are listed from zeroth order to highest.
+///
+/// The coefficients are captured once and every incoming point is mapped to the value of the
+/// polynomial there. The returned stream is a 1:1 map of `points`: it yields an item exactly
+/// when `points` does, so backpressure propagates unchanged to the upstream stream.
+///
+/// # Examples
+///
+/// ```
+/// use futures::{executor::block_on, stream, StreamExt};
+/// use polyeval::horner_stream;
+///
+/// let points = stream::iter([0.0, 1.0, 2.0]);
+/// let values: Vec<f64> = block_on(horner_stream(vec![2.0, 3.0, 4.0], points).collect());
+///
+/// assert_eq!(values, [2.0, 9.0, 24.0]);
+/// ```
+pub fn horner_stream<S>(coeffs: Vec<f64>, points: S) -> impl Stream<Item = f64>
+where
+    S: Stream<Item = f64>,
+{
+    points.map(move |x| crate::horner(x, &coeffs))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_horner_stream() {
+        use super::horner_stream;
+        use crate::horner;
+        use futures::{executor::block_on, stream, StreamExt};
+
+        let coeffs = vec![1., 2., 3., 4., 5.];
+        let points: Vec<f64> = (0..32).map(|x| x as f64).collect();
+        let values: Vec<f64> =
+            block_on(horner_stream(coeffs.clone(), stream::iter(points.clone())).collect());
+        assert_eq!(values.len(), points.len());
+        for (&x, y) in points.iter().zip(values) {
+            assert_eq!(y, horner(x, &coeffs));
+        }
+
+        let empty: Vec<f64> = block_on(horner_stream(coeffs, stream::empty()).collect());
+        assert!(empty.is_empty());
+    }
+}
@@ -0,0 +1,107 @@
+//! Construction of coefficient tables from a generating rule.
+
+/// Build a table of coefficients from a generating rule.
+///
+/// Returns the array `[f(0), f(1), ..., f(N-1)]`, which can be passed to
+/// [`horner_array`](fn@crate::horner_array).
+///
+/// This function cannot be `const`, because calling function pointers is not allowed in
+/// `const fn` on stable Rust. To build a table at compile time, use the
+/// [`fill_coeffs!`](macro@crate::fill_coeffs) macro instead.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{fill_coeffs, horner_array};
+///
+/// let coeffs: [f64; 4] = fill_coeffs(|k| (k + 1) as f64);
+///
+/// assert_eq!(coeffs, [1.0, 2.0, 3.0, 4.0]);
+/// assert_eq!(horner_array(2.0, &coeffs), 1.0 + 2.0 * (2.0 + 2.0 * (3.0 + 2.0 * 4.0)));
+/// ```
+pub fn fill_coeffs<const N: usize>(f: fn(usize) -> f64) -> [f64; N] {
+    core::array::from_fn(f)
+}
+
+/// Build a table of coefficients from a generating rule, in a `const` context.
+///
+/// `fill_coeffs!(N, |k| expr)` evaluates to the array `[f(0), f(1), ..., f(N-1)]` of `f64`,
+/// where `f(k)` is the value of `expr` with `k` bound to the index (of type `usize`).
+///
+/// The expansion is a plain `while` loop, hence it can initialize a `const` item, provided that
+/// `expr` is itself evaluable at compile time: it may call `const fn`s, but not closures or
+/// function pointers, and it can't use iterators or trait methods.
+///
+/// # Examples
+///
+/// ```
+/// use polyeval::{fill_coeffs, horner_array};
+///
+/// const fn recip_factorial(k: usize) -> f64 {
+///     let mut f = 1.0;
+///     let mut i = 1;
+///     while i <= k {
+///         f /= i as f64;
+///         i += 1;
+///     }
+///     f
+/// }
+///
+/// // Taylor series of the exponential function.
+/// const EXP: [f64; 18] = fill_coeffs!(18, |k| recip_factorial(k));
+///
+/// assert_eq!(EXP[..4], [1.0, 1.0, 0.5, 1.0 / 6.0]);
+/// assert!((horner_array(1.0, &EXP) - std::f64::consts::E).abs() < 1e-15);
+/// ```
+#[macro_export]
+macro_rules! fill_coeffs {
+    ($n:expr, |$k:ident| $f:expr $(,)?) => {{
+        let mut coeffs = [0.0_f64; $n];
+        let mut i = 0;
+        while i < coeffs.len() {
+            let $k: usize = i;
+            coeffs[i] = $f;
+            i += 1;
+        }
+        coeffs
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    const fn recip_factorial(k: usize) -> f64 {
+        let mut f = 1.;
+        let mut i = 1;
+        while i <= k {
+            f /= i as f64;
+            i += 1;
+        }
+        f
+    }
+
+    #[test]
+    fn test_fill_coeffs() {
+        use super::fill_coeffs;
+        assert_eq!(fill_coeffs::<0>(|_| 1.), [] as [f64; 0]);
+        assert_eq!(fill_coeffs::<5>(|k| k as f64), [0., 1., 2., 3., 4.]);
+        assert_eq!(
+            fill_coeffs::<8>(recip_factorial),
+            fill_coeffs!(8, |k| recip_factorial(k))
+        );
+    }
+
+    #[test]
+    fn test_macro_fill_coeffs() {
+        use crate::horner_array;
+        const EMPTY: [f64; 0] = fill_coeffs!(0, |_k| 1.);
+        const SQUARES: [f64; 5] = fill_coeffs!(5, |k| (k * k) as f64);
+        const EXP: [f64; 20] = fill_coeffs!(20, |k| recip_factorial(k),);
+        assert_eq!(EMPTY, [] as [f64; 0]);
+        assert_eq!(SQUARES, [0., 1., 4., 9., 16.]);
+        assert_eq!(EXP[..4], [1., 1., 0.5, 1. / 6.]);
+        for x in -8..=8 {
+            let x = x as f64 / 8.;
+            assert!((horner_array(x, &EXP) - x.exp()).abs() < 1e-15);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Straight-line evaluation of polynomials of low degree, enabled by the `unroll-*` features.
+
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// Expands to the unrolled steps `acc * x + c` of Horner's method, from the last coefficient.
+macro_rules! unrolled {
+    ($x:ident; $c:ident) => { T::zero() * $x + $c };
+    ($x:ident; $c:ident, $($rest:ident),+) => { unrolled!($x; $($rest),+) * $x + $c };
+}
+
+/// Evaluate a polynomial with straight-line code for Horner's method,
+/// or return `None` if it has more coefficients than the chosen unroll level.
+///
+/// The operations are the same as those of the loop in [`horner`](fn@crate::horner),
+/// in the same order, so the results are identical.
+#[inline(always)]
+#[track_caller]
+pub(crate) fn horner_unrolled<T>(x: &T, coeffs: &[T]) -> Option<T>
+where
+    T: Zero,
+    T: for<'a> Add<&'a T, Output = T>,
+    T: for<'a> Mul<&'a T, Output = T>,
+{
+    Some(match coeffs {
+        [] => T::zero(),
+        [c0] => unrolled!(x; c0),
+        [c0, c1] => unrolled!(x; c0, c1),
+        [c0, c1, c2] => unrolled!(x; c0, c1, c2),
+        [c0, c1, c2, c3] => unrolled!(x; c0, c1, c2, c3),
+        #[cfg(any(feature = "unroll-8", feature = "unroll-16"))]
+        [c0, c1, c2, c3, c4] => unrolled!(x; c0, c1, c2, c3, c4),
+        #[cfg(any(feature = "unroll-8", feature = "unroll-16"))]
+        [c0, c1, c2, c3, c4, c5] => unrolled!(x; c0, c1, c2, c3, c4, c5),
+        #[cfg(any(feature = "unroll-8", feature = "unroll-16"))]
+        [c0, c1, c2, c3, c4, c5, c6] => unrolled!(x; c0, c1, c2, c3, c4, c5, c6),
+        #[cfg(any(feature = "unroll-8", feature = "unroll-16"))]
+        [c0, c1, c2, c3, c4, c5, c6, c7] => unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7),
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14)
+        }
+        #[cfg(feature = "unroll-16")]
+        [c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14, c15] => {
+            unrolled!(x; c0, c1, c2, c3, c4, c5, c6, c7, c8, c9, c10, c11, c12, c13, c14, c15)
+        }
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    /// The largest number of coefficients evaluated with straight-line code.
+    const UNROLL: usize = if cfg!(feature = "unroll-16") {
+        16
+    } else if cfg!(feature = "unroll-8") {
+        8
+    } else {
+        4
+    };
+
+    /// Plain Horner's method, as a reference.
+    fn fold<T>(x: T, coeffs: &[T]) -> T
+    where
+        T: num_traits::Zero,
+        T: for<'a> core::ops::Add<&'a T, Output = T>,
+        T: for<'a> core::ops::Mul<&'a T, Output = T>,
+    {
+        coeffs.iter().rfold(T::zero(), |acc: T, c: &T| acc * &x + c)
+    }
+
+    #[test]
+    fn test_horner_unrolled() {
+        use super::horner_unrolled;
+        use crate::horner;
+        let coeffs: [i64; 20] = core::array::from_fn(|k| (k as i64 * 7) % 11 - 5);
+        for x in -8..8 {
+            for n in 0..=coeffs.len() {
+                let unrolled = horner_unrolled(&x, &coeffs[..n]);
+                // the straight-line code is used exactly up to the unroll level
+                assert_eq!(unrolled.is_some(), n <= UNROLL);
+                if let Some(value) = unrolled {
+                    assert_eq!(value, fold(x, &coeffs[..n]));
+                }
+                assert_eq!(horner(x, &coeffs[..n]), fold(x, &coeffs[..n]));
+            }
+        }
+        // identical also for the special floating point values
+        let specials = [
+            0.,
+            -0.,
+            1.5,
+            -2.25,
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::NAN,
+        ];
+        let coeffs: [f64; 16] = core::array::from_fn(|k| specials[(k * 3) % specials.len()]);
+        for x in specials {
+            for n in 0..=UNROLL {
+                let unrolled = horner_unrolled(&x, &coeffs[..n]).unwrap();
+                assert_eq!(unrolled.to_bits(), fold(x, &coeffs[..n]).to_bits());
+            }
+        }
+    }
+}
@@ -94,3 +94,79 @@ fn test_macro_estrin_fma() {
     assert_eq!(estrin_fma!(x; [2, 3, 4]), 2 + x * (3 + x * 4));
     assert_eq!(estrin_fma!(x; [2, 3, 4,]), 2 + x * (3 + x * 4));
 }
+
+#[test]
+fn test_estrin_iterative() {
+    use polyeval::estrin_iterative;
+
+    let x = 7;
+    let mut scratch = [0; 2];
+
+    assert_eq!(estrin_iterative(x, &[], &mut scratch), 0);
+    assert_eq!(estrin_iterative(x, &[0], &mut scratch), 0);
+    assert_eq!(
+        estrin_iterative(x, &[2, 3, 4], &mut scratch),
+        2 + x * (3 + x * 4)
+    );
+    assert_eq!(
+        estrin_iterative(x, &[2, 3, 4, 5], &mut scratch),
+        2 + x * (3 + x * (4 + x * 5))
+    );
+}
+
+#[test]
+fn test_estrin_array() {
+    use polyeval::estrin_array;
+
+    let x = 7;
+
+    assert_eq!(estrin_array(x, &[]), 0);
+    assert_eq!(estrin_array(x, &[0]), 0);
+    assert_eq!(estrin_array(x, &[2, 3, 4]), 2 + x * (3 + x * 4));
+    assert_eq!(
+        estrin_array::<i32, 4>(x, &[2, 3, 4, 5]),
+        2 + x * (3 + x * (4 + x * 5))
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_estrin() {
+    use polyeval::estrin;
+
+    let x = 7;
+
+    assert_eq!(estrin(x, &[]), 0);
+    assert_eq!(estrin(x, &[0]), 0);
+    assert_eq!(estrin(x, &[2, 3, 4]), 2 + x * (3 + x * 4));
+    assert_eq!(estrin(x, &[2, 3, 4, 5]), 2 + x * (3 + x * (4 + x * 5)));
+}
+
+#[test]
+fn test_macro_horner_poly() {
+    use polyeval::horner_poly;
+
+    let x = 7;
+
+    assert_eq!(horner_poly!(x; 0; degree = 0), 0);
+    assert_eq!(horner_poly!(x; [0,]; degree = 0), 0);
+    assert_eq!(horner_poly!(x; 2, 3, 4; degree = 2), 2 + x * (3 + x * 4));
+    assert_eq!(horner_poly!(x; [2, 3, 4]; degree = 2), 2 + x * (3 + x * 4));
+    assert_eq!(
+        horner_poly!(let x + 1; 2, 3, 4,; degree = 2),
+        2 + 8 * (3 + 8 * 4)
+    );
+}
+
+#[test]
+fn test_macro_horner_closure() {
+    use polyeval::horner_closure;
+
+    let p = horner_closure!(2, 3, 4);
+    let q = horner_closure!([2, 3, 4,]);
+
+    for x in 0..8 {
+        assert_eq!(p(x), 2 + x * (3 + x * 4));
+        assert_eq!(q(x), p(x));
+    }
+}
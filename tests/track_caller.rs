@@ -0,0 +1,71 @@
+//! The panics in the evaluation functions are reported at the location of the call.
+
+use std::panic::{self, Location};
+use std::sync::Mutex;
+
+/// Runs `f`, which must panic, and returns the location reported by the panic.
+fn panic_location(f: impl FnOnce() + panic::UnwindSafe) -> (String, u32) {
+    // the panic hook is global, so the tests must not replace it concurrently
+    static LOCK: Mutex<()> = Mutex::new(());
+    static LOCATION: Mutex<Option<(String, u32)>> = Mutex::new(None);
+    let _guard = LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|info| {
+        let location = info.location().unwrap();
+        *LOCATION.lock().unwrap() = Some((location.file().to_owned(), location.line()));
+    }));
+    let result = panic::catch_unwind(f);
+    panic::set_hook(hook);
+    assert!(result.is_err(), "the function didn't panic");
+    LOCATION.lock().unwrap().take().unwrap()
+}
+
+/// Returns the location of the caller, shifted by `lines`.
+#[track_caller]
+fn here(lines: u32) -> (String, u32) {
+    let location = Location::caller();
+    (location.file().to_owned(), location.line() + lines)
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_overflow_location() {
+    use polyeval::{estrin_iterative, horner, horner_array};
+
+    let coeffs = [1, 2, 3, 4, i64::MAX];
+
+    let expected = here(2);
+    let location = panic_location(|| {
+        horner(2, &coeffs);
+    });
+    assert_eq!(location, expected);
+
+    let expected = here(2);
+    let location = panic_location(|| {
+        horner_array(2, &coeffs);
+    });
+    assert_eq!(location, expected);
+
+    let expected = here(2);
+    let location = panic_location(|| {
+        estrin_iterative(2, &coeffs, &mut [0; 3]);
+    });
+    assert_eq!(location, expected);
+}
+
+#[test]
+fn test_assertion_location() {
+    use polyeval::{estrin_iterative, horner_hash};
+
+    let expected = here(2);
+    let location = panic_location(|| {
+        estrin_iterative(2, &[1, 2, 3], &mut []);
+    });
+    assert_eq!(location, expected);
+
+    let expected = here(2);
+    let location = panic_location(|| {
+        horner_hash(2, &[1, 2, 3], 0);
+    });
+    assert_eq!(location, expected);
+}